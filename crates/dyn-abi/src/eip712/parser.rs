@@ -1,10 +1,10 @@
 //! EIP-712 specific parsing structures.
 
 use crate::{
-    eip712::resolver::{PropertyDef, TypeDef},
+    eip712::resolver::{PropertyDef, Resolver, TypeDef},
     Error,
 };
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use alloy_sol_type_parser::{Error as TypeParserError, TypeSpecifier};
 
 /// A property is a type and a name. Of the form `type name`. E.g.
@@ -17,10 +17,30 @@ pub struct PropDef<'a> {
     pub name: &'a str,
 }
 
-impl PropDef<'_> {
-    /// Convert to an owned `PropertyDef`
+impl<'a> PropDef<'a> {
+    /// Instantiate a new property from its type specifier and name.
+    #[inline]
+    pub const fn new(ty: TypeSpecifier<'a>, name: &'a str) -> Self {
+        Self { ty, name }
+    }
+
+    /// Convert to an owned `PropertyDef`.
+    ///
+    /// Integer and fixed-point aliases are normalized to their canonical form,
+    /// e.g. `uint` to `uint256`, as required for the type hash.
     pub fn to_owned(&self) -> PropertyDef {
-        PropertyDef::new(self.ty.span, self.name).unwrap()
+        PropertyDef::new(canonical_type_name(&self.ty), self.name).unwrap()
+    }
+}
+
+/// Returns the type name of `ty` with integer and fixed-point aliases
+/// normalized to their canonical form, e.g. `uint[2]` to `uint256[2]`.
+pub(crate) fn canonical_type_name(ty: &TypeSpecifier<'_>) -> String {
+    let span = ty.span;
+    match ty.stem.span() {
+        stem @ ("uint" | "int") => format!("{stem}256{}", &span[stem.len()..]),
+        stem @ ("ufixed" | "fixed") => format!("{stem}128x18{}", &span[stem.len()..]),
+        _ => span.into(),
     }
 }
 
@@ -62,11 +82,24 @@ impl ComponentType<'_> {
     }
 }
 
-// This impl handles
 impl<'a> TryFrom<&'a str> for ComponentType<'a> {
     type Error = Error;
 
     fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let (component, rest) = Self::parse_prefix(input)?;
+        if !rest.is_empty() {
+            return Err(Error::TypeParser(TypeParserError::invalid_type_string(
+                input,
+            )))
+        }
+        Ok(component)
+    }
+}
+
+impl<'a> ComponentType<'a> {
+    /// Parses a single component type from the start of `input`, returning it
+    /// along with the rest of the input after its closing parenthesis.
+    fn parse_prefix(input: &'a str) -> Result<(Self, &'a str), Error> {
         let (name, props_str) = input
             .split_once('(')
             .ok_or_else(|| Error::TypeParser(TypeParserError::invalid_type_string(input)))?;
@@ -74,33 +107,37 @@ impl<'a> TryFrom<&'a str> for ComponentType<'a> {
         let mut props = vec![];
         let mut depth = 1; // 1 to account for the ( in the split above
         let mut last = 0;
+        let mut end = None;
 
-        for (i, c) in props_str.chars().enumerate() {
+        for (i, c) in props_str.char_indices() {
             match c {
                 '(' => depth += 1,
                 ')' => {
                     depth -= 1;
                     if depth == 0 {
                         props.push(props_str[last..i].try_into()?);
-                        last = i + 1;
+                        end = Some(i + 1);
                         break
                     }
                 }
-                ',' => {
-                    if depth == 1 {
-                        props.push(props_str[last..i].try_into()?);
-                        last = i + 1;
-                    }
+                ',' if depth == 1 => {
+                    props.push(props_str[last..i].try_into()?);
+                    last = i + 1;
                 }
                 _ => {}
             }
         }
 
-        Ok(Self {
-            span: &input[..last + name.len() + 1],
+        // unbalanced parentheses
+        let end =
+            end.ok_or_else(|| Error::TypeParser(TypeParserError::invalid_type_string(input)))?;
+        let (span, rest) = input.split_at(name.len() + 1 + end);
+        let component = Self {
+            span,
             type_name: name,
             props,
-        })
+        };
+        Ok((component, rest))
     }
 }
 
@@ -118,8 +155,9 @@ impl<'a> TryFrom<&'a str> for EncodeType<'a> {
         let mut types = vec![];
         let mut remaining = input;
 
-        while let Ok(t) = ComponentType::try_from(remaining) {
-            remaining = &remaining[t.span.len()..];
+        while !remaining.is_empty() {
+            let (t, rest) = ComponentType::parse_prefix(remaining)?;
+            remaining = rest;
             types.push(t);
         }
 
@@ -127,6 +165,95 @@ impl<'a> TryFrom<&'a str> for EncodeType<'a> {
     }
 }
 
+impl<'a> EncodeType<'a> {
+    /// Computes the maximum nesting depth of the `primary` type, following
+    /// property references to the other component types.
+    ///
+    /// A component type that only has non-struct properties has a depth of 1.
+    /// Returns 0 if `primary` is not one of the component types. References
+    /// that would form a cycle are not followed.
+    ///
+    /// This can be used to reject overly complex schemas before hashing.
+    pub fn max_depth(&self, primary: &str) -> usize {
+        let mut depths = BTreeMap::new();
+        let mut stack = Vec::new();
+        self.types
+            .iter()
+            .find(|t| t.type_name == primary)
+            .map(|t| self.depth_of(t.type_name, &mut depths, &mut stack))
+            .unwrap_or(0)
+    }
+
+    fn depth_of(
+        &self,
+        type_name: &'a str,
+        depths: &mut BTreeMap<&'a str, usize>,
+        stack: &mut Vec<&'a str>,
+    ) -> usize {
+        if let Some(&depth) = depths.get(type_name) {
+            return depth
+        }
+        let Some(component) = self.types.iter().find(|t| t.type_name == type_name) else {
+            return 0
+        };
+        if stack.contains(&type_name) {
+            return 0
+        }
+
+        stack.push(type_name);
+        let depth = 1 + component
+            .props
+            .iter()
+            .filter_map(|prop| prop.ty.stem.as_root())
+            .map(|root| self.depth_of(root.span(), depths, stack))
+            .max()
+            .unwrap_or(0);
+        stack.pop();
+
+        depths.insert(type_name, depth);
+        depth
+    }
+
+    /// True if both encode types describe the same type graph rooted at
+    /// `primary`, regardless of the order of their component types.
+    ///
+    /// Only `primary` and the types it references, transitively, are
+    /// compared: both are canonicalized with
+    /// [`encode_type_for`](Self::encode_type_for), and component types that
+    /// `primary` does not reference are ignored. Returns false if `primary` or
+    /// a type it references is missing from either.
+    pub fn canonical_eq(&self, other: &EncodeType<'_>, primary: &str) -> bool {
+        match (
+            self.encode_type_for(primary),
+            other.encode_type_for(primary),
+        ) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Returns the EIP-712 `encodeType` string of the `primary` type: its
+    /// definition followed by the definitions of the types it references,
+    /// transitively, sorted by name.
+    ///
+    /// Unreferenced component types are omitted. Errors if `primary` or a
+    /// type it references is missing.
+    ///
+    /// <https://eips.ethereum.org/EIPS/eip-712#definition-of-encodetype>
+    pub fn encode_type_for(&self, primary: &str) -> Result<String, Error> {
+        let mut resolver = Resolver::default();
+        for component in &self.types {
+            resolver.ingest(component.to_owned());
+        }
+        resolver.encode_type(primary)
+    }
+
+    /// Returns the total number of properties across all component types.
+    pub fn total_property_count(&self) -> usize {
+        self.types.iter().map(|t| t.props.len()).sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,6 +276,175 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_component_type_trailing_input() {
+        ComponentType::try_from("Foo(uint256 a)garbage").unwrap_err();
+        ComponentType::try_from("Foo(uint256 a").unwrap_err();
+        ComponentType::try_from("Foo(uint256 a,(bool,bool) b").unwrap_err();
+
+        EncodeType::try_from("Foo(uint256 a)garbage").unwrap_err();
+        EncodeType::try_from("Foo(uint256 a)Bar(bool b").unwrap_err();
+        assert_eq!(
+            EncodeType::try_from("Foo(uint256 a)Bar(bool b)")
+                .unwrap()
+                .types
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_canonical_aliases() {
+        let prop = PropDef::try_from("uint[2] value").unwrap().to_owned();
+        assert_eq!(prop.type_name(), "uint256[2]");
+        let prop = PropDef::try_from("fixed value").unwrap().to_owned();
+        assert_eq!(prop.type_name(), "fixed128x18");
+        let prop = PropDef::try_from("uint8 value").unwrap().to_owned();
+        assert_eq!(prop.type_name(), "uint8");
+
+        let type_hash = |s: &str| {
+            let mut resolver = crate::eip712::Resolver::default();
+            resolver.ingest_string(s).unwrap();
+            resolver.type_hash("Mail").unwrap()
+        };
+        assert_eq!(
+            type_hash("Mail(uint value,int[] values)"),
+            type_hash("Mail(uint256 value,int256[] values)")
+        );
+    }
+
+    #[test]
+    fn test_prop_def_new() {
+        let ty = TypeSpecifier::parse("Person[2]").unwrap();
+        let prop = PropDef::new(ty.clone(), "people");
+        assert_eq!(prop, PropDef::try_from("Person[2] people").unwrap());
+
+        let owned = PropertyDef::from_type_specifier(&ty, "people").unwrap();
+        assert_eq!(owned, prop.to_owned());
+        assert_eq!(owned.root_type_name(), "Person");
+
+        let component = ComponentType {
+            span: "Group(Person[2] people,string name)",
+            type_name: "Group",
+            props: vec![
+                prop,
+                PropDef::new(TypeSpecifier::parse("string").unwrap(), "name"),
+            ],
+        };
+        assert_eq!(
+            component,
+            ComponentType::try_from("Group(Person[2] people,string name)").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_property_def_from_type_specifier() {
+        let spec = |s| TypeSpecifier::parse(s).unwrap();
+        let prop = PropertyDef::from_type_specifier(&spec("uint[2]"), "values").unwrap();
+        assert_eq!(prop.type_name(), "uint256[2]");
+        assert_eq!(
+            prop,
+            PropDef::try_from("uint[2] values").unwrap().to_owned()
+        );
+
+        PropertyDef::from_type_specifier(&spec("uint256"), "").unwrap_err();
+        PropertyDef::from_type_specifier(&spec("uint256"), "two words").unwrap_err();
+        PropertyDef::from_type_specifier(&spec("uint256"), "1value").unwrap_err();
+
+        let mut structural = Resolver::default();
+        structural.ingest(
+            TypeDef::new(
+                "Mail",
+                vec![
+                    PropertyDef::from_type_specifier(&spec("uint"), "value").unwrap(),
+                    PropertyDef::from_type_specifier(&spec("int[]"), "rates").unwrap(),
+                ],
+            )
+            .unwrap(),
+        );
+        let mut string = Resolver::default();
+        string
+            .ingest_string("Mail(uint value,int[] rates)")
+            .unwrap();
+        assert_eq!(
+            structural.type_hash("Mail").unwrap(),
+            string.type_hash("Mail").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_canonical_eq() {
+        let a = EncodeType::try_from(EXAMPLE).unwrap();
+        let b = EncodeType::try_from(
+            "Person(address wallet,string name)Transaction(Person from,Person to,Asset tx)Asset(address token,uint256 amount)",
+        )
+        .unwrap();
+        assert_ne!(a, b);
+        assert!(a.canonical_eq(&b, "Transaction"));
+        assert!(b.canonical_eq(&a, "Transaction"));
+        assert!(!a.canonical_eq(&b, "Missing"));
+
+        let c = EncodeType::try_from(
+            "Transaction(Person from,Person to,Asset tx)Asset(address token,uint128 amount)Person(address wallet,string name)",
+        )
+        .unwrap();
+        assert!(!a.canonical_eq(&c, "Transaction"));
+        let d = EncodeType::try_from("Transaction(Person from,Person to,Asset tx)").unwrap();
+        assert!(!a.canonical_eq(&d, "Transaction"));
+
+        // types that `primary` does not reference are ignored
+        let e = EncodeType::try_from(
+            "Unused(bool flag)Transaction(Person from,Person to,Asset tx)Asset(address token,uint256 amount)Person(address wallet,string name)",
+        )
+        .unwrap();
+        assert!(a.canonical_eq(&e, "Transaction"));
+        assert!(e.canonical_eq(&a, "Transaction"));
+        assert!(a.canonical_eq(&e, "Person"));
+        assert!(!a.canonical_eq(&e, "Unused"));
+    }
+
+    #[test]
+    fn test_encode_type_for() {
+        let encode_type = EncodeType::try_from(
+            "Person(address wallet,string name)Unused(bool b)Transaction(Person from,Person to,Asset tx)Asset(address token,uint amount)",
+        )
+        .unwrap();
+        assert_eq!(
+            encode_type.encode_type_for("Transaction").unwrap(),
+            "Transaction(Person from,Person to,Asset tx)Asset(address token,uint256 amount)Person(address wallet,string name)"
+        );
+        assert_eq!(
+            encode_type.encode_type_for("Person").unwrap(),
+            "Person(address wallet,string name)"
+        );
+        assert_eq!(
+            encode_type.encode_type_for("Missing"),
+            Err(Error::missing_type("Missing"))
+        );
+
+        let dangling = EncodeType::try_from("Mail(Person from,string contents)").unwrap();
+        assert_eq!(
+            dangling.encode_type_for("Mail"),
+            Err(Error::missing_type("Person"))
+        );
+    }
+
+    #[test]
+    fn test_complexity_metrics() {
+        let encode_type = EncodeType::try_from(EXAMPLE).unwrap();
+        assert_eq!(encode_type.max_depth("Transaction"), 2);
+        assert_eq!(encode_type.max_depth("Person"), 1);
+        assert_eq!(encode_type.max_depth("Missing"), 0);
+        assert_eq!(encode_type.total_property_count(), 7);
+
+        let nested = EncodeType::try_from("A(B[] b,uint256 x)B(C c)C(bool flag)").unwrap();
+        assert_eq!(nested.max_depth("A"), 3);
+        assert_eq!(nested.total_property_count(), 4);
+
+        let cyclic = EncodeType::try_from("A(B b)B(A a)").unwrap();
+        assert_eq!(cyclic.max_depth("A"), 2);
+    }
+
     #[test]
     fn test_encode_type() {
         assert_eq!(
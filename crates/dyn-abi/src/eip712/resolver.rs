@@ -1,5 +1,7 @@
 use crate::{
-    eip712::typed_data::Eip712Types, eip712_parser::EncodeType, resolve::ResolveSolType,
+    eip712::typed_data::Eip712Types,
+    eip712_parser::{canonical_type_name, EncodeType},
+    resolve::ResolveSolType,
     DynSolType, DynSolValue, Error, Result,
 };
 use alloc::{
@@ -9,7 +11,7 @@ use alloc::{
     vec::Vec,
 };
 use alloy_primitives::{keccak256, B256};
-use alloy_sol_type_parser::{RootType, TypeSpecifier, TypeStem};
+use alloy_sol_type_parser::{is_valid_identifier, RootType, TypeSpecifier, TypeStem};
 use alloy_sol_types::SolStruct;
 use core::{cmp::Ordering, fmt};
 use serde::{Deserialize, Deserializer, Serialize};
@@ -64,6 +66,20 @@ impl PropertyDef {
         }
     }
 
+    /// Instantiate a new name-type pair from an already parsed type
+    /// specifier, checking that the name is a valid identifier.
+    ///
+    /// Integer and fixed-point aliases are normalized to their canonical form,
+    /// e.g. `uint` to `uint256`, as when parsing the type from a string.
+    #[inline]
+    pub fn from_type_specifier<N: Into<String>>(ty: &TypeSpecifier<'_>, name: N) -> Result<Self> {
+        let name = name.into();
+        if !is_valid_identifier(&name) {
+            return Err(Error::invalid_property_def(&name))
+        }
+        Ok(Self::new_unchecked(canonical_type_name(ty), name))
+    }
+
     /// Returns the name of the property.
     #[inline]
     pub fn name(&self) -> &str {
@@ -1,10 +1,10 @@
-use crate::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
+use crate::{AbiItem, Constructor, Error, Event, Fallback, Function, Param, Receive};
 use alloc::{
     collections::{btree_map, btree_map::Values},
     string::String,
     vec::Vec,
 };
-use alloy_primitives::Bytes;
+use alloy_primitives::{Bytes, Selector};
 use btree_map::BTreeMap;
 use core::{fmt, iter, iter::Flatten};
 use serde::{
@@ -13,6 +13,14 @@ use serde::{
     Deserialize, Deserializer, Serialize,
 };
 
+macro_rules! entry_and_push {
+    ($map:expr, $v:expr) => {
+        $map.entry($v.name.clone())
+            .or_default()
+            .push($v.into_owned())
+    };
+}
+
 /// The JSON contract ABI, as specified in the [Solidity ABI spec][ref].
 ///
 /// [ref]: https://docs.soliditylang.org/en/latest/abi-spec.html#json
@@ -167,6 +175,38 @@ impl JsonAbi {
         self.functions.get(name).map(Vec::as_slice)
     }
 
+    /// Returns all the functions with the given name, i.e. all of its
+    /// overloads. Empty if there are none.
+    ///
+    /// See also [`function`](Self::function), which borrows the overloads as a
+    /// slice.
+    #[inline]
+    pub fn functions_by_name(&self, name: &str) -> Vec<&Function> {
+        self.function(name).unwrap_or_default().iter().collect()
+    }
+
+    /// Returns all the events with the given name. Empty if there are none.
+    ///
+    /// See also [`event`](Self::event), which borrows the events as a slice.
+    #[inline]
+    pub fn events_by_name(&self, name: &str) -> Vec<&Event> {
+        self.event(name).unwrap_or_default().iter().collect()
+    }
+
+    /// Gets the function with the given signature, e.g. `foo(uint256)`.
+    ///
+    /// This can be used to select a specific function among overloads. The
+    /// signature is parsed, so parameter names and non-canonical types like
+    /// `uint` are accepted, and matched by selector. Returns `None` if the
+    /// signature is invalid or no function matches.
+    pub fn function_by_signature(&self, signature: &str) -> Option<&Function> {
+        let parsed = Function::parse(signature).ok()?;
+        let selector = parsed.selector();
+        self.function(&parsed.name)?
+            .iter()
+            .find(|function| function.selector() == selector)
+    }
+
     /// Gets all the events with the given name.
     #[inline]
     pub fn event(&self, name: &str) -> Option<&[Event]> {
@@ -196,6 +236,163 @@ impl JsonAbi {
     pub fn errors(&self) -> Flatten<Values<'_, String, Vec<Error>>> {
         self.errors.values().flatten()
     }
+
+    /// Returns the function and error selectors that are shared by more than
+    /// one item, along with the colliding items.
+    ///
+    /// Functions are only compared with functions, and errors with errors.
+    /// The collisions are computed from the current items on each call, so
+    /// they reflect any changes made to the ABI since it was built. An ABI
+    /// collected with [`FromIterator`] does not fail on collisions, so this
+    /// can be used to check its integrity afterwards.
+    pub fn collisions(&self) -> Vec<(Selector, Vec<AbiItem<'_>>)> {
+        fn collect<'a, T: 'a>(
+            items: impl Iterator<Item = &'a T>,
+            selector: impl Fn(&T) -> Selector,
+            out: &mut Vec<(Selector, Vec<AbiItem<'a>>)>,
+        ) where
+            AbiItem<'a>: From<&'a T>,
+        {
+            let mut by_selector = BTreeMap::<Selector, Vec<AbiItem<'a>>>::new();
+            for item in items {
+                by_selector
+                    .entry(selector(item))
+                    .or_default()
+                    .push(item.into());
+            }
+            out.extend(by_selector.into_iter().filter(|(_, items)| items.len() > 1));
+        }
+
+        let mut collisions = Vec::new();
+        collect(self.functions(), Function::selector, &mut collisions);
+        collect(self.errors(), Error::selector, &mut collisions);
+        collisions
+    }
+
+    /// Removes all the functions and errors with the given selector, and
+    /// returns them.
+    ///
+    /// Functions are returned before errors, each in name order.
+    pub fn remove_by_selector(&mut self, selector: Selector) -> Vec<AbiItem<'static>> {
+        fn remove<T>(
+            map: &mut BTreeMap<String, Vec<T>>,
+            selector: Selector,
+            item_selector: impl Fn(&T) -> Selector,
+            removed: &mut Vec<AbiItem<'static>>,
+        ) where
+            AbiItem<'static>: From<T>,
+        {
+            map.retain(|_, items| {
+                let (matching, rest) = core::mem::take(items)
+                    .into_iter()
+                    .partition::<Vec<_>, _>(|item| item_selector(item) == selector);
+                removed.extend(matching.into_iter().map(AbiItem::from));
+                *items = rest;
+                !items.is_empty()
+            });
+        }
+
+        let mut removed = Vec::new();
+        remove(
+            &mut self.functions,
+            selector,
+            Function::selector,
+            &mut removed,
+        );
+        remove(&mut self.errors, selector, Error::selector, &mut removed);
+        removed
+    }
+
+    /// Removes the `internalType` of all the parameters of the contract,
+    /// including the tuple components.
+    ///
+    /// This produces a minimal ABI, and normalizes ABIs that only differ by
+    /// their internal types, e.g. before comparing or hashing them.
+    pub fn strip_internal_types(&mut self) {
+        fn strip(params: &mut [Param]) {
+            for param in params {
+                param.internal_type = None;
+                strip(&mut param.components);
+            }
+        }
+
+        if let Some(constructor) = &mut self.constructor {
+            strip(&mut constructor.inputs);
+        }
+        for function in self.functions.values_mut().flatten() {
+            strip(&mut function.inputs);
+            strip(&mut function.outputs);
+        }
+        for event in self.events.values_mut().flatten() {
+            for param in &mut event.inputs {
+                param.internal_type = None;
+                strip(&mut param.components);
+            }
+        }
+        for error in self.errors.values_mut().flatten() {
+            strip(&mut error.inputs);
+        }
+    }
+
+    /// Returns the selectors of all the functions and errors of the contract,
+    /// paired with their signatures, e.g. `transfer(address,uint256)`.
+    ///
+    /// The table is sorted by selector, then by signature, so that its order
+    /// is stable across ABIs with the same items.
+    pub fn selector_table(&self) -> Vec<(Selector, String)> {
+        let functions = self.functions().map(|f| (f.selector(), f.signature()));
+        let errors = self.errors().map(|e| (e.selector(), e.signature()));
+        let mut table: Vec<_> = functions.chain(errors).collect();
+        table.sort_unstable();
+        table
+    }
+
+    /// Returns a new ABI with clones of the items for which `f` returns
+    /// `true`, e.g. only the view functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::{AbiItem, JsonAbi, StateMutability};
+    /// let abi: JsonAbi = [
+    ///     "function balanceOf(address owner) view returns (uint256)",
+    ///     "function transfer(address to, uint256 amount) returns (bool)",
+    ///     "event Transfer(address indexed from, address indexed to, uint256 value)",
+    /// ]
+    /// .iter()
+    /// .map(|s| AbiItem::parse(s))
+    /// .collect::<Result<_, _>>()?;
+    /// let views = abi.filter(|item| item.state_mutability() == Some(StateMutability::View));
+    /// assert_eq!(views.len(), 1);
+    /// assert!(views.function("balanceOf").is_some());
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn filter<F: FnMut(&AbiItem<'_>) -> bool>(&self, mut f: F) -> Self {
+        self.items().filter(|item| f(item)).collect()
+    }
+}
+
+impl<'a> FromIterator<AbiItem<'a>> for JsonAbi {
+    /// Collects the items into an ABI.
+    ///
+    /// This never fails: if more than one constructor, fallback or receive
+    /// function is given, the last one is kept, and items with colliding
+    /// selectors are all kept. Collisions are not checked during collection;
+    /// use [`JsonAbi::collisions`] to compute them afterwards.
+    fn from_iter<T: IntoIterator<Item = AbiItem<'a>>>(iter: T) -> Self {
+        let mut abi = Self::new();
+        for item in iter {
+            match item {
+                AbiItem::Constructor(c) => abi.constructor = Some(c.into_owned()),
+                AbiItem::Fallback(f) => abi.fallback = Some(f.into_owned()),
+                AbiItem::Receive(r) => abi.receive = Some(r.into_owned()),
+                AbiItem::Function(f) => entry_and_push!(abi.functions, f),
+                AbiItem::Event(e) => entry_and_push!(abi.events, e),
+                AbiItem::Error(e) => entry_and_push!(abi.errors, e),
+            }
+        }
+        abi
+    }
 }
 
 macro_rules! next_item {
@@ -344,14 +541,6 @@ macro_rules! set_if_none {
     }};
 }
 
-macro_rules! entry_and_push {
-    ($map:expr, $v:expr) => {
-        $map.entry($v.name.clone())
-            .or_default()
-            .push($v.into_owned())
-    };
-}
-
 struct JsonAbiVisitor;
 
 impl<'de> Visitor<'de> for JsonAbiVisitor {
@@ -0,0 +1,55 @@
+use crate::Function;
+use alloc::collections::BTreeMap;
+use alloy_primitives::Selector;
+
+/// Routes calldata to functions by their selector.
+///
+/// Implemented for maps from selectors to functions.
+///
+/// # Examples
+///
+/// ```
+/// # use alloy_json_abi::{AbiDispatch, Function};
+/// # use std::collections::BTreeMap;
+/// let transfer = Function::parse("transfer(address to, uint256 amount)")?;
+/// let table = BTreeMap::from([(transfer.selector(), transfer)]);
+///
+/// let calldata = [0xa9, 0x05, 0x9c, 0xbb, 0x01, 0x02];
+/// let (function, args) = table.dispatch(&calldata).unwrap();
+/// assert_eq!(function.name, "transfer");
+/// assert_eq!(args, [0x01, 0x02]);
+/// # Ok::<_, alloy_json_abi::parser::Error>(())
+/// ```
+pub trait AbiDispatch {
+    /// Returns the function with the given selector, if any.
+    fn function_by_selector(&self, selector: &Selector) -> Option<&Function>;
+
+    /// Returns the function called by `calldata`, along with the calldata
+    /// following the selector, i.e. the encoded arguments.
+    ///
+    /// Returns `None` if `calldata` is shorter than a selector or if no
+    /// function matches.
+    fn dispatch<'a>(&self, calldata: &'a [u8]) -> Option<(&Function, &'a [u8])> {
+        if calldata.len() < 4 {
+            return None
+        }
+        let (selector, args) = calldata.split_at(4);
+        let function = self.function_by_selector(&Selector::from_slice(selector))?;
+        Some((function, args))
+    }
+}
+
+impl AbiDispatch for BTreeMap<Selector, Function> {
+    #[inline]
+    fn function_by_selector(&self, selector: &Selector) -> Option<&Function> {
+        self.get(selector)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: std::hash::BuildHasher> AbiDispatch for std::collections::HashMap<Selector, Function, S> {
+    #[inline]
+    fn function_by_selector(&self, selector: &Selector) -> Option<&Function> {
+        self.get(selector)
+    }
+}
@@ -39,6 +39,20 @@ pub enum InternalType {
     },
 }
 
+impl InternalType {
+    /// Returns an estimate of the heap memory used by the type's strings.
+    pub(crate) fn heap_size(&self) -> usize {
+        match self {
+            Self::AddressPayable(s) | Self::Contract(s) => s.capacity(),
+            Self::Enum { contract, ty }
+            | Self::Struct { contract, ty }
+            | Self::Other { contract, ty } => {
+                contract.as_ref().map_or(0, String::capacity) + ty.capacity()
+            }
+        }
+    }
+}
+
 impl From<BorrowedInternalType<'_>> for InternalType {
     #[inline]
     fn from(borrowed: BorrowedInternalType<'_>) -> Self {
@@ -158,6 +172,46 @@ impl InternalType {
         }
     }
 
+    /// Returns the name of the struct, without its contract qualifier and
+    /// array suffixes, if this type is a struct.
+    ///
+    /// For example, `struct Lib.Item[]` has the struct name `Item`.
+    #[inline]
+    pub fn struct_name(&self) -> Option<&str> {
+        self.as_struct().map(|(_, ty)| strip_array_suffixes(ty))
+    }
+
+    /// Returns the name of the enum, without its contract qualifier and array
+    /// suffixes, if this type is an enum.
+    #[inline]
+    pub fn enum_name(&self) -> Option<&str> {
+        self.as_enum().map(|(_, ty)| strip_array_suffixes(ty))
+    }
+
+    /// Returns the name of the contract, without array suffixes, if this type
+    /// is a contract.
+    #[inline]
+    pub fn contract_name(&self) -> Option<&str> {
+        self.as_contract().map(strip_array_suffixes)
+    }
+
+    /// Returns the name of the type, without any contract qualifier and array
+    /// suffixes.
+    ///
+    /// This is the struct, enum or contract name, or the name of the "other"
+    /// type, e.g. a user-defined value type or a regular Solidity type.
+    /// Returns `None` for `address payable`, which is not a named type.
+    #[inline]
+    pub fn type_name(&self) -> Option<&str> {
+        match self {
+            Self::AddressPayable(_) => None,
+            Self::Contract(ty)
+            | Self::Enum { ty, .. }
+            | Self::Struct { ty, .. }
+            | Self::Other { ty, .. } => Some(strip_array_suffixes(ty)),
+        }
+    }
+
     /// Return a [`TypeSpecifier`] describing the struct if this type is a
     /// struct.
     #[inline]
@@ -212,6 +266,11 @@ impl InternalType {
     }
 }
 
+#[inline]
+fn strip_array_suffixes(ty: &str) -> &str {
+    ty.split_once('[').map_or(ty, |(name, _)| name)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum BorrowedInternalType<'a> {
     AddressPayable(&'a str),
@@ -410,4 +469,29 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn type_names() {
+        let ty = InternalType::parse("struct Lib.Item[][2]").unwrap();
+        assert_eq!(ty.struct_name(), Some("Item"));
+        assert_eq!(ty.type_name(), Some("Item"));
+        assert_eq!(ty.enum_name(), None);
+        assert_eq!(ty.contract_name(), None);
+
+        let ty = InternalType::parse("enum Side").unwrap();
+        assert_eq!(ty.enum_name(), Some("Side"));
+        assert_eq!(ty.type_name(), Some("Side"));
+        assert_eq!(ty.struct_name(), None);
+
+        let ty = InternalType::parse("contract IERC20[]").unwrap();
+        assert_eq!(ty.contract_name(), Some("IERC20"));
+        assert_eq!(ty.type_name(), Some("IERC20"));
+
+        let ty = InternalType::parse("Lib.Price[3]").unwrap();
+        assert_eq!(ty.type_name(), Some("Price"));
+        assert_eq!(ty.struct_name(), None);
+
+        let ty = InternalType::parse("address payable[]").unwrap();
+        assert_eq!(ty.type_name(), None);
+    }
 }
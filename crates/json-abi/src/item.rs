@@ -1,6 +1,13 @@
-use crate::{param::Param, utils::*, EventParam, StateMutability};
-use alloc::{borrow::Cow, string::String, vec::Vec};
+use crate::{param::Param, to_sol::ToSol, utils::*, EventParam, StateMutability, TypeTree};
+use alloc::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
 use alloy_primitives::{keccak256, Selector, B256};
+use alloy_sol_type_parser as parser;
+use core::fmt::{self, Write};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 // Serde order:
@@ -77,17 +84,19 @@ macro_rules! abi_items {
             $name(Cow<'a, $name>),
         )*}
 
+        /// The kind of a JSON ABI item, without any of its data.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum AbiItemKind {$(
+            #[doc = concat!("A JSON ABI [`", stringify!($name), "`].")]
+            $name,
+        )*}
+
         #[doc(hidden)]
         mod private {
             use super::*;
 
             $(
-                #[derive(Clone, Serialize, Deserialize)]
-                #[serde(rename_all = "camelCase")]
-                #[repr(C)]
-                pub(super) struct $name {$(
-                    $field: $type,
-                )*}
+                private_item!($name { $($field: $type,)* });
             )*
 
             #[derive(Serialize, Deserialize)]
@@ -97,6 +106,69 @@ macro_rules! abi_items {
                 $name(Cow<'a, self::$name>),
             )*}
         }
+
+        // Check the layout invariants of the transmutes at compile time.
+        $(
+            const _: () = assert_same_layout!($name, private::$name);
+        )*
+        const _: () = assert_same_layout!(AbiItem<'static>, private::AbiItem<'static>);
+    };
+}
+
+macro_rules! assert_same_layout {
+    ($a:ty, $b:ty) => {
+        assert!(
+            core::mem::size_of::<$a>() == core::mem::size_of::<$b>()
+                && core::mem::align_of::<$a>() == core::mem::align_of::<$b>(),
+            concat!(
+                "`",
+                stringify!($a),
+                "` and `",
+                stringify!($b),
+                "` must have the same layout"
+            ),
+        )
+    };
+}
+
+macro_rules! private_item {
+    // events must not have outputs, which would otherwise be silently ignored;
+    // an empty `outputs` array is still accepted, as some tools emit it
+    (Event { $($field:ident : $type:ty,)* }) => {
+        #[derive(Clone, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase", try_from = "EventRepr")]
+        #[repr(C)]
+        pub(super) struct Event {$(
+            $field: $type,
+        )*}
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct EventRepr {
+            $($field: $type,)*
+            #[serde(default)]
+            outputs: Vec<serde::de::IgnoredAny>,
+        }
+
+        impl TryFrom<EventRepr> for Event {
+            type Error = &'static str;
+
+            #[inline]
+            fn try_from(repr: EventRepr) -> Result<Self, Self::Error> {
+                if !repr.outputs.is_empty() {
+                    return Err("events cannot have outputs")
+                }
+                Ok(Self { $($field: repr.$field,)* })
+            }
+        }
+    };
+    ($name:ident { $($field:ident : $type:ty,)* }) => {
+        #[derive(Clone, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        #[repr(C)]
+        pub(super) struct $name {$(
+            $field: $type,
+        )*}
     };
 }
 
@@ -206,6 +278,381 @@ impl<'a, 'r> From<&'r AbiItem<'a>> for &'r private::AbiItem<'a> {
 }
 
 impl AbiItem<'_> {
+    /// Parses a single human-readable ABI item, e.g.
+    /// `function balanceOf(address owner) view returns (uint256)`.
+    ///
+    /// The item must be prefixed by its keyword: one of `function`, `event`,
+    /// `error`, `constructor`, `fallback` or `receive`.
+    ///
+    /// Parameters of library-qualified struct types, e.g. `IERC20.Foo memory
+    /// x`, are parsed as `tuple`s without components, with the struct name as
+    /// their internal type, since the struct's fields are not part of the
+    /// signature. The item's signature and selector are therefore invalid,
+    /// and [`validate_spec`](Self::validate_spec) reports
+    /// [`SpecViolation::TupleWithoutComponents`] for them. Use
+    /// [`Error::parse_with_registry`] to resolve their components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::AbiItem;
+    /// let item = AbiItem::parse("event Transfer(address indexed from, address indexed to, uint256 value)")?;
+    /// assert_eq!(item.debug_name(), "Event");
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn parse(s: &str) -> parser::Result<AbiItem<'static>> {
+        let s = s.trim();
+        let (keyword, body) = s
+            .find(|c: char| !parser::is_id_continue(c))
+            .map(|i| s.split_at(i))
+            .unwrap_or((s, ""));
+        match keyword {
+            "function" => Function::parse(body).map(Into::into),
+            "event" => Event::parse(body).map(Into::into),
+            "error" => Error::parse(body).map(Into::into),
+            "constructor" | "fallback" | "receive" => {
+                let (_, inputs, rest) = parse_signature(s, parse_param)?;
+                let (state_mutability, _) = parse_function_modifiers(rest, false)?;
+                match keyword {
+                    "constructor" => Ok(Constructor {
+                        inputs,
+                        state_mutability,
+                    }
+                    .into()),
+                    _ if !inputs.is_empty() => Err(parser::Error::new(format_args!(
+                        "{keyword} functions cannot have inputs"
+                    ))),
+                    "fallback" => Ok(Fallback { state_mutability }.into()),
+                    _ => Ok(Receive { state_mutability }.into()),
+                }
+            }
+            _ => Err(parser::Error::new(format_args!(
+                "invalid human-readable ABI item: {s:?}"
+            ))),
+        }
+    }
+
+    /// Parses a human-readable ABI document, with one [item](Self::parse) per
+    /// line, recovering from invalid lines.
+    ///
+    /// Returns the successfully parsed items, in order, along with the
+    /// 1-based line number and parsing error of each invalid line. Blank lines
+    /// are ignored.
+    pub fn parse_human_readable_lossy(
+        s: &str,
+    ) -> (Vec<AbiItem<'static>>, Vec<(usize, parser::Error)>) {
+        let mut items = Vec::new();
+        let mut errors = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue
+            }
+            match AbiItem::parse(line) {
+                Ok(item) => items.push(item),
+                Err(e) => errors.push((i + 1, e)),
+            }
+        }
+        (items, errors)
+    }
+
+    /// Deserializes a JSON array of ABI items, skipping the items that fail to
+    /// deserialize instead of failing entirely.
+    ///
+    /// Returns the valid items, and the errors along with the index of their
+    /// item in the array. Errors only if the input is not a JSON array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::AbiItem;
+    /// let json = r#"[
+    ///     { "type": "event", "name": "Foo", "inputs": [], "anonymous": false },
+    ///     { "type": "event", "name": "Bar" },
+    ///     { "type": "error", "name": "Baz", "inputs": [] }
+    /// ]"#;
+    /// let (items, errors) = AbiItem::deserialize_lenient(json)?;
+    /// assert_eq!(items.len(), 2);
+    /// assert_eq!(errors[0].0, 1);
+    /// # Ok::<_, serde_json::Error>(())
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[allow(clippy::type_complexity)]
+    pub fn deserialize_lenient(
+        json: &str,
+    ) -> Result<(Vec<AbiItem<'static>>, Vec<(usize, serde_json::Error)>), serde_json::Error> {
+        let values: Vec<serde_json::Value> = serde_json::from_str(json)?;
+        let mut items = Vec::with_capacity(values.len());
+        let mut errors = Vec::new();
+        for (i, value) in values.iter().enumerate() {
+            match AbiItem::deserialize(value) {
+                Ok(item) => items.push(item.into_owned()),
+                Err(e) => errors.push((i, e)),
+            }
+        }
+        Ok((items, errors))
+    }
+
+    /// Deserializes a single item from JSON, rejecting any unknown field.
+    ///
+    /// The default [`Deserialize`] implementation ignores fields that are not
+    /// part of the ABI specification, such as the legacy `constant` and
+    /// `payable` flags or compiler-specific annotations, so that ABIs from any
+    /// source can be ingested. This instead errors on the first field that is
+    /// not used by the item's type, including in its parameters, which is
+    /// useful to enforce clean ABIs, e.g. in CI.
+    ///
+    /// The item must also pass [`validate_spec`](Self::validate_spec). In
+    /// particular, legacy types with invalid sizes, such as `bytes0` or
+    /// `uint7`, are accepted by the default implementation but rejected here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::AbiItem;
+    /// let json = r#"{ "type": "error", "name": "Foo", "inputs": [], "constant": false }"#;
+    /// assert!(serde_json::from_str::<AbiItem<'_>>(json).is_ok());
+    /// assert!(AbiItem::from_json_strict(json).is_err());
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn from_json_strict(s: &str) -> Result<AbiItem<'static>, serde_json::Error> {
+        use serde::de::Error as _;
+        use serde_json::Value;
+
+        fn check_fields(value: &Value, allowed: &[&str], path: &str) -> serde_json::Result<()> {
+            let Some(object) = value.as_object() else {
+                return Ok(())
+            };
+            if let Some(key) = object.keys().find(|key| !allowed.contains(&key.as_str())) {
+                return Err(serde_json::Error::custom(format_args!(
+                    "unknown field `{key}` in {path}"
+                )))
+            }
+            Ok(())
+        }
+
+        fn check_params(value: Option<&Value>, event: bool, path: &str) -> serde_json::Result<()> {
+            const PARAM: &[&str] = &["name", "type", "internalType", "components"];
+            const EVENT_PARAM: &[&str] = &["name", "type", "internalType", "components", "indexed"];
+            let Some(params) = value.and_then(Value::as_array) else {
+                return Ok(())
+            };
+            for (i, param) in params.iter().enumerate() {
+                let path = format!("{path}[{i}]");
+                check_fields(param, if event { EVENT_PARAM } else { PARAM }, &path)?;
+                check_params(
+                    param.get("components"),
+                    false,
+                    &format!("{path}.components"),
+                )?;
+            }
+            Ok(())
+        }
+
+        let value: Value = serde_json::from_str(s)?;
+        let allowed: &[&str] = match value.get("type").and_then(Value::as_str) {
+            Some("constructor") => &["type", "inputs", "stateMutability"],
+            Some("fallback" | "receive") => &["type", "stateMutability"],
+            Some("function") => &["type", "name", "inputs", "outputs", "stateMutability"],
+            Some("event") => &["type", "name", "inputs", "anonymous"],
+            Some("error") => &["type", "name", "inputs"],
+            _ => &[],
+        };
+        if !allowed.is_empty() {
+            check_fields(&value, allowed, "item")?;
+            let event = allowed.contains(&"anonymous");
+            check_params(value.get("inputs"), event, "inputs")?;
+            check_params(value.get("outputs"), false, "outputs")?;
+        }
+        let item = AbiItem::deserialize(&value)?.into_owned();
+        if let Err(violations) = item.validate_spec() {
+            let mut msg = String::new();
+            for (i, violation) in violations.iter().enumerate() {
+                if i > 0 {
+                    msg.push_str("; ");
+                }
+                write!(msg, "{violation}").unwrap();
+            }
+            return Err(serde_json::Error::custom(msg))
+        }
+        Ok(item)
+    }
+
+    /// Parses the ABI items of a contract artifact, e.g. as output by Forge or
+    /// Hardhat: a JSON object with the ABI array in its `abi` field.
+    ///
+    /// The other fields of the artifact are ignored. Errors if the `abi` field
+    /// is missing or any of its items is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::AbiItem;
+    /// let json = r#"{
+    ///     "contractName": "Foo",
+    ///     "abi": [{ "type": "error", "name": "Bar", "inputs": [] }],
+    ///     "bytecode": "0x"
+    /// }"#;
+    /// let items = AbiItem::parse_artifact(json)?;
+    /// assert_eq!(items[0].name().unwrap(), "Bar");
+    ///
+    /// let err = AbiItem::parse_artifact(r#"{ "bytecode": "0x" }"#).unwrap_err();
+    /// assert!(err.to_string().contains("missing field `abi`"));
+    /// # Ok::<_, serde_json::Error>(())
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn parse_artifact(json: &str) -> Result<Vec<AbiItem<'static>>, serde_json::Error> {
+        #[derive(Deserialize)]
+        struct Artifact<'a> {
+            #[serde(borrow)]
+            abi: Vec<AbiItem<'a>>,
+        }
+
+        let artifact: Artifact<'_> = serde_json::from_str(json)?;
+        Ok(artifact.abi.into_iter().map(AbiItem::into_owned).collect())
+    }
+
+    /// Converts the item into an owned item, cloning its data if it is
+    /// borrowed.
+    #[inline]
+    pub fn into_owned(self) -> AbiItem<'static> {
+        match self {
+            Self::Constructor(item) => item.into_owned().into(),
+            Self::Fallback(item) => item.into_owned().into(),
+            Self::Receive(item) => item.into_owned().into(),
+            Self::Function(item) => item.into_owned().into(),
+            Self::Event(item) => item.into_owned().into(),
+            Self::Error(item) => item.into_owned().into(),
+        }
+    }
+
+    /// Clones the item into an owned item, which does not borrow from `self`.
+    ///
+    /// Unlike [`Clone`], which keeps borrowed data borrowed and therefore
+    /// returns an item with the same lifetime, this always clones the data,
+    /// so the result can outlive the original borrow.
+    #[inline]
+    pub fn clone_owned(&self) -> AbiItem<'static> {
+        match self {
+            Self::Constructor(item) => Constructor::clone(item).into(),
+            Self::Fallback(item) => Fallback::clone(item).into(),
+            Self::Receive(item) => Receive::clone(item).into(),
+            Self::Function(item) => Function::clone(item).into(),
+            Self::Event(item) => Event::clone(item).into(),
+            Self::Error(item) => Error::clone(item).into(),
+        }
+    }
+
+    /// Converts the item into a function, cloning it if it is borrowed, or
+    /// returns the item back if it is not a function.
+    #[inline]
+    pub fn try_into_function(self) -> Result<Function, Self> {
+        match self {
+            Self::Function(item) => Ok(item.into_owned()),
+            item => Err(item),
+        }
+    }
+
+    /// Converts the item into an event, cloning it if it is borrowed, or
+    /// returns the item back if it is not an event.
+    #[inline]
+    pub fn try_into_event(self) -> Result<Event, Self> {
+        match self {
+            Self::Event(item) => Ok(item.into_owned()),
+            item => Err(item),
+        }
+    }
+
+    /// Returns an estimate of the heap memory used by the item's data, in
+    /// bytes: the capacity of its strings and vectors, recursively.
+    ///
+    /// This does not include the allocator's overhead, nor the size of the
+    /// item itself. The data is counted even if the item is borrowed.
+    pub fn heap_size(&self) -> usize {
+        match self {
+            Self::Constructor(c) => params_heap_size(&c.inputs, c.inputs.capacity()),
+            Self::Fallback(_) | Self::Receive(_) => 0,
+            Self::Function(f) => {
+                f.name.capacity()
+                    + params_heap_size(&f.inputs, f.inputs.capacity())
+                    + params_heap_size(&f.outputs, f.outputs.capacity())
+            }
+            Self::Event(e) => {
+                e.name.capacity()
+                    + e.inputs.capacity() * core::mem::size_of::<EventParam>()
+                    + e.inputs.iter().map(EventParam::heap_size).sum::<usize>()
+            }
+            Self::Error(e) => e.name.capacity() + params_heap_size(&e.inputs, e.inputs.capacity()),
+        }
+    }
+
+    /// Returns the entry points through which the contract can receive ether:
+    /// the payable functions, the fallback function if it is payable, and the
+    /// receive function.
+    ///
+    /// The constructor is not included, since it is not callable once the
+    /// contract is deployed.
+    pub fn payable_entry_points(items: &[Self]) -> Vec<&Self> {
+        items
+            .iter()
+            .filter(|item| match item {
+                Self::Receive(_) => true,
+                Self::Fallback(_) | Self::Function(_) => {
+                    item.state_mutability() == Some(StateMutability::Payable)
+                }
+                Self::Constructor(_) | Self::Event(_) | Self::Error(_) => false,
+            })
+            .collect()
+    }
+
+    /// Merges two lists of items, e.g. a proxy's ABI as `overlay` over its
+    /// implementation's as `base`.
+    ///
+    /// Items of `overlay` take precedence over the items of `base` they
+    /// collide with: functions and errors with the same selector, events with
+    /// the same topic, and the constructor, fallback and receive functions.
+    /// The remaining items of `base` come first, in order, followed by all
+    /// the items of `overlay`.
+    pub fn merge_abis_prefer<'a>(
+        base: Vec<AbiItem<'a>>,
+        overlay: Vec<AbiItem<'a>>,
+    ) -> Vec<AbiItem<'a>> {
+        let overlaid: BTreeSet<_> = overlay.iter().map(AbiItem::collision_key).collect();
+        let mut merged: Vec<_> = base
+            .into_iter()
+            .filter(|item| !overlaid.contains(&item.collision_key()))
+            .collect();
+        merged.extend(overlay);
+        merged
+    }
+
+    /// Returns `true` if this item's selector collides with `other`'s.
+    ///
+    /// Functions and errors are compared with each other by selector, and
+    /// events with each other by topic. Other combinations of items cannot
+    /// collide, so this returns `false` for them.
+    pub fn selector_collides_with(&self, other: &AbiItem<'_>) -> bool {
+        let selector = |item: &AbiItem<'_>| match item {
+            AbiItem::Function(f) => Some(f.selector()),
+            AbiItem::Error(e) => Some(e.selector()),
+            _ => None,
+        };
+        match (self, other) {
+            (Self::Event(a), AbiItem::Event(b)) => a.selector() == b.selector(),
+            _ => matches!((selector(self), selector(other)), (Some(a), Some(b)) if a == b),
+        }
+    }
+
+    /// The key under which two items of the same ABI collide.
+    fn collision_key(&self) -> (AbiItemKind, Vec<u8>) {
+        let id = match self {
+            Self::Constructor(_) | Self::Fallback(_) | Self::Receive(_) => Vec::new(),
+            Self::Function(item) => item.selector().to_vec(),
+            Self::Event(item) => item.selector().to_vec(),
+            Self::Error(item) => item.selector().to_vec(),
+        };
+        (self.kind(), id)
+    }
+
     /// Returns the debug name of the item.
     #[inline]
     pub const fn debug_name(&self) -> &'static str {
@@ -219,6 +666,58 @@ impl AbiItem<'_> {
         }
     }
 
+    /// Returns the kind of the item.
+    #[inline]
+    pub const fn kind(&self) -> AbiItemKind {
+        match self {
+            AbiItem::Constructor(_) => AbiItemKind::Constructor,
+            AbiItem::Fallback(_) => AbiItemKind::Fallback,
+            AbiItem::Receive(_) => AbiItemKind::Receive,
+            AbiItem::Function(_) => AbiItemKind::Function,
+            AbiItem::Event(_) => AbiItemKind::Event,
+            AbiItem::Error(_) => AbiItemKind::Error,
+        }
+    }
+
+    /// Returns a short, stable identifier of the item, for logging.
+    ///
+    /// Functions and errors are identified by their selector, e.g.
+    /// `fn:a9059cbb` and `err:82b42900`, and events by the first 4 bytes of
+    /// their topic, e.g. `ev:ddf252ad`. Constructors, fallbacks and receives
+    /// are identified as `ctor`, `fallback` and `receive` respectively.
+    pub fn short_id(&self) -> String {
+        let (prefix, id) = match self {
+            Self::Constructor(_) => return "ctor".into(),
+            Self::Fallback(_) => return "fallback".into(),
+            Self::Receive(_) => return "receive".into(),
+            Self::Function(item) => ("fn:", item.selector()),
+            Self::Event(item) => ("ev:", Selector::from_slice(&item.selector()[..4])),
+            Self::Error(item) => ("err:", item.selector()),
+        };
+        let mut s = String::with_capacity(prefix.len() + 8);
+        s.push_str(prefix);
+        s.push_str(&alloy_primitives::hex::encode(id));
+        s
+    }
+
+    /// Returns a flat `(kind, name, signature)` record of the item, e.g. for
+    /// tabular export.
+    ///
+    /// The name is `None` for constructors, fallbacks and receives. The
+    /// signature is the canonical signature of functions, events and errors,
+    /// the [pseudo-signature](Constructor::signature) of constructors, and
+    /// `None` for fallbacks and receives, which take no inputs.
+    pub fn to_record(&self) -> (AbiItemKind, Option<String>, Option<String>) {
+        let signature = match self {
+            Self::Constructor(item) => Some(item.signature()),
+            Self::Fallback(_) | Self::Receive(_) => None,
+            Self::Function(item) => Some(item.signature()),
+            Self::Event(item) => Some(item.signature()),
+            Self::Error(item) => Some(item.signature()),
+        };
+        (self.kind(), self.name().cloned(), signature)
+    }
+
     /// Returns an immutable reference to the name of the item.
     #[inline]
     pub fn name(&self) -> Option<&String> {
@@ -243,6 +742,50 @@ impl AbiItem<'_> {
         }
     }
 
+    /// Replaces the name of the item with the result of `f`, if the item has
+    /// a name.
+    ///
+    /// Unlike [`name_mut`](Self::name_mut), a borrowed item is only cloned if
+    /// the new name differs from the current one.
+    pub fn map_name<F: FnOnce(&str) -> String>(&mut self, f: F) {
+        let Some(name) = self.name() else { return };
+        let new_name = f(name);
+        if new_name != *name {
+            if let Some(name) = self.name_mut() {
+                *name = new_name;
+            }
+        }
+    }
+
+    /// Returns an owned copy of the item with its name replaced by `name`.
+    ///
+    /// Items without a name (constructor, fallback and receive) are returned
+    /// unchanged. Errors if `name` is not a valid Solidity identifier.
+    pub fn with_name(self, name: impl Into<String>) -> parser::Result<AbiItem<'static>> {
+        let name = name.into();
+        validate_name(&name)?;
+        Ok(match self {
+            Self::Function(item) => Function {
+                name,
+                ..item.into_owned()
+            }
+            .into(),
+            Self::Event(item) => Event {
+                name,
+                ..item.into_owned()
+            }
+            .into(),
+            Self::Error(item) => Error {
+                name,
+                ..item.into_owned()
+            }
+            .into(),
+            item @ (Self::Constructor(_) | Self::Fallback(_) | Self::Receive(_)) => {
+                item.into_owned()
+            }
+        })
+    }
+
     /// Returns the state mutability of the item.
     #[inline]
     pub fn state_mutability(&self) -> Option<StateMutability> {
@@ -255,6 +798,19 @@ impl AbiItem<'_> {
         }
     }
 
+    /// Returns the state mutability of the item, defaulting to
+    /// [`NonPayable`](StateMutability::NonPayable) for items that do not have
+    /// one.
+    ///
+    /// This is a convenience for code that treats all items uniformly: events
+    /// and errors genuinely lack a state mutability, which is reported by
+    /// [`state_mutability`](Self::state_mutability).
+    #[inline]
+    pub fn state_mutability_or_default(&self) -> StateMutability {
+        self.state_mutability()
+            .unwrap_or(StateMutability::NonPayable)
+    }
+
     /// Returns a mutable reference to the state mutability of the item.
     ///
     /// Clones the item if it is not already owned.
@@ -269,6 +825,16 @@ impl AbiItem<'_> {
         }
     }
 
+    /// Returns whether the item is an anonymous event, or `None` if the item
+    /// is not an event.
+    #[inline]
+    pub fn is_anonymous(&self) -> Option<bool> {
+        match self {
+            Self::Event(item) => Some(item.anonymous),
+            _ => None,
+        }
+    }
+
     /// Returns an immutable reference to the inputs of the item.
     ///
     /// Use [`event_inputs`](Self::event_inputs) for events instead.
@@ -282,6 +848,75 @@ impl AbiItem<'_> {
         }
     }
 
+    /// Returns the type tree of each top-level input of the item, including
+    /// event inputs.
+    ///
+    /// Errors if a type is not a valid type specifier.
+    pub fn input_trees(&self) -> parser::Result<Vec<TypeTree>> {
+        match self.event_inputs() {
+            Some(inputs) => inputs
+                .iter()
+                .map(|param| TypeTree::new(&param.ty, &param.components))
+                .collect(),
+            None => self
+                .inputs()
+                .into_iter()
+                .flatten()
+                .map(TypeTree::from_param)
+                .collect(),
+        }
+    }
+
+    /// Returns the type tree of each output of the item. Only functions have
+    /// outputs.
+    ///
+    /// Errors if a type is not a valid type specifier.
+    pub fn output_trees(&self) -> parser::Result<Vec<TypeTree>> {
+        self.outputs()
+            .into_iter()
+            .flatten()
+            .map(TypeTree::from_param)
+            .collect()
+    }
+
+    /// Returns the index and the input with the given name, if any.
+    ///
+    /// Only the top-level inputs of functions, errors and constructors are
+    /// searched. Unnamed inputs never match.
+    #[inline]
+    pub fn input_by_name(&self, name: &str) -> Option<(usize, &Param)> {
+        if name.is_empty() {
+            return None
+        }
+        self.inputs()?
+            .iter()
+            .enumerate()
+            .find(|(_, param)| param.name == name)
+    }
+
+    /// Returns `true` if a non-empty name is shared by more than one of the
+    /// top-level inputs, or by more than one of the outputs, of the item.
+    ///
+    /// Unnamed parameters are ignored. This can be used by binding generators
+    /// to detect when parameters cannot be passed by name.
+    pub fn has_duplicate_param_names(&self) -> bool {
+        fn has_duplicates<'a>(names: impl IntoIterator<Item = &'a str>) -> bool {
+            let mut seen = BTreeSet::new();
+            names
+                .into_iter()
+                .filter(|name| !name.is_empty())
+                .any(|name| !seen.insert(name))
+        }
+
+        self.inputs().map_or(false, |params| {
+            has_duplicates(params.iter().map(|p| p.name.as_str()))
+        }) || self.event_inputs().map_or(false, |params| {
+            has_duplicates(params.iter().map(|p| p.name.as_str()))
+        }) || self.outputs().map_or(false, |params| {
+            has_duplicates(params.iter().map(|p| p.name.as_str()))
+        })
+    }
+
     /// Returns a mutable reference to the inputs of the item.
     ///
     /// Clones the item if it is not already owned.
@@ -354,9 +989,331 @@ impl AbiItem<'_> {
             | Self::Event(_) => None,
         }
     }
+
+    /// Formats this item as a human-readable signature, e.g.
+    /// `event Transfer(address indexed from, address indexed to, uint256
+    /// value)`.
+    ///
+    /// The output can be parsed back with [`AbiItem::parse`]. See
+    /// [`Function::try_to_human_readable`] for the possible errors.
+    pub fn try_to_human_readable(&self) -> parser::Result<String> {
+        let mut out = String::new();
+        match self {
+            Self::Constructor(c) => {
+                out.push_str("constructor");
+                human_readable_params(&c.inputs, &mut out)?;
+                if c.state_mutability == StateMutability::Payable {
+                    out.push_str(" payable");
+                }
+            }
+            Self::Fallback(f) => {
+                out.push_str("fallback() external");
+                if f.state_mutability == StateMutability::Payable {
+                    out.push_str(" payable");
+                }
+            }
+            Self::Receive(_) => out.push_str("receive() external payable"),
+            Self::Function(f) => return f.try_to_human_readable(),
+            Self::Event(e) => {
+                validate_name(&e.name)?;
+                out.push_str("event ");
+                out.push_str(&e.name);
+                human_readable_event_params(&e.inputs, &mut out)?;
+                if e.anonymous {
+                    out.push_str(" anonymous");
+                }
+            }
+            Self::Error(e) => {
+                validate_name(&e.name)?;
+                out.push_str("error ");
+                out.push_str(&e.name);
+                human_readable_params(&e.inputs, &mut out)?;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Formats this item as a Solidity interface declaration, e.g.
+    /// `function transfer(address to, uint256 amount) external returns
+    /// (bool);`.
+    ///
+    /// Functions, fallbacks and receives are declared `external`. Returns
+    /// `None` for constructors, which cannot be declared in an interface.
+    pub fn to_solidity(&self) -> Option<String> {
+        let mut out = String::new();
+        match self {
+            Self::Constructor(_) => return None,
+            Self::Fallback(f) => f.to_sol(&mut out),
+            Self::Receive(r) => r.to_sol(&mut out),
+            Self::Function(f) => f.to_sol(&mut out),
+            Self::Event(e) => e.to_sol(&mut out),
+            Self::Error(e) => e.to_sol(&mut out),
+        }
+        Some(out)
+    }
+
+    /// Formats the items as a human-readable ABI, with one item per line.
+    ///
+    /// Items are ordered by kind: constructor, receive, fallback, functions,
+    /// events, and then errors. Items of the same kind keep their relative
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::AbiItem;
+    /// let items = [
+    ///     AbiItem::parse("event Transfer(address indexed from, address indexed to, uint256 value)")?,
+    ///     AbiItem::parse("function totalSupply() view returns (uint256)")?,
+    /// ];
+    /// assert_eq!(
+    ///     AbiItem::fmt_human_readable_abi(&items)?,
+    ///     "\
+    /// function totalSupply() view returns (uint256)
+    /// event Transfer(address indexed from, address indexed to, uint256 value)
+    /// "
+    /// );
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn fmt_human_readable_abi(items: &[AbiItem<'_>]) -> parser::Result<String> {
+        let order = |item: &&AbiItem<'_>| match item.kind() {
+            AbiItemKind::Constructor => 0,
+            AbiItemKind::Receive => 1,
+            AbiItemKind::Fallback => 2,
+            AbiItemKind::Function => 3,
+            AbiItemKind::Event => 4,
+            AbiItemKind::Error => 5,
+        };
+        let mut sorted: Vec<_> = items.iter().collect();
+        sorted.sort_by_key(order);
+
+        let mut out = String::new();
+        for item in sorted {
+            out.push_str(&item.try_to_human_readable()?);
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    /// Computes a stable hash of the items, to detect interface changes.
+    ///
+    /// The algorithm is:
+    /// 1. serialize each item to compact JSON, as in
+    ///    [`Function::to_fragment_json`];
+    /// 2. sort the items by kind, in the order constructor, fallback, receive,
+    ///    function, event, error, and then by their JSON bytes;
+    /// 3. concatenate the JSON strings, without any separator;
+    /// 4. hash the result with `keccak256`.
+    ///
+    /// The hash does not depend on the order of the items, but does depend on
+    /// every serialized field, including parameter names and internal types.
+    #[cfg(feature = "serde_json")]
+    pub fn abi_hash(items: &[AbiItem<'_>]) -> B256 {
+        let mut serialized: Vec<_> = items
+            .iter()
+            .map(|item| {
+                let json = serde_json::to_string(item).expect("serializing an item cannot fail");
+                (item.kind(), json)
+            })
+            .collect();
+        serialized.sort_unstable();
+        let preimage: String = serialized.into_iter().map(|(_, json)| json).collect();
+        keccak256(preimage.as_bytes())
+    }
+
+    /// Returns advisory warnings about the item.
+    ///
+    /// These are not errors: the item is valid, but looks suspicious. Tools
+    /// can choose whether to surface them.
+    pub fn lint(&self) -> Vec<LintWarning> {
+        let mut warnings = Vec::new();
+        if let Self::Function(f) = self {
+            let getter_name = f.name.strip_prefix("get").map_or(false, |rest| {
+                rest.is_empty() || rest.starts_with(|c: char| c.is_ascii_uppercase() || c == '_')
+            });
+            if getter_name
+                && f.inputs.is_empty()
+                && !f.outputs.is_empty()
+                && f.state_mutability == StateMutability::NonPayable
+            {
+                warnings.push(LintWarning::SuspiciousMutability);
+            }
+        }
+        warnings
+    }
+
+    /// Checks that the tuple-ness of every parameter's type matches the
+    /// presence of components, recursively: `tuple` types (including arrays
+    /// of tuples) must have components, and other types must not.
+    ///
+    /// The error contains the path to the first inconsistent parameter, e.g.
+    /// `inputs[1].components[0]`.
+    pub fn validate_tuple_consistency(&self) -> parser::Result<()> {
+        let mut path = String::new();
+        if let Some(inputs) = self.event_inputs() {
+            for (i, param) in inputs.iter().enumerate() {
+                path.clear();
+                write!(path, "inputs[{i}]").unwrap();
+                validate_tuple_consistency(&mut path, &param.ty, &param.components)?;
+            }
+        }
+        let lists = [("inputs", self.inputs()), ("outputs", self.outputs())];
+        for (list, params) in lists {
+            for (i, param) in params.into_iter().flatten().enumerate() {
+                path.clear();
+                write!(path, "{list}[{i}]").unwrap();
+                validate_tuple_consistency(&mut path, &param.ty, &param.components)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the item follows the [Solidity ABI JSON specification][ref],
+    /// returning all the violations found.
+    ///
+    /// This checks that names are valid identifiers, that types are valid and
+    /// their tuple-ness matches the presence of components, that state
+    /// mutabilities are allowed for the item's kind, and that events do not
+    /// have too many indexed parameters.
+    ///
+    /// [ref]: https://docs.soliditylang.org/en/latest/abi-spec.html#json
+    pub fn validate_spec(&self) -> Result<(), Vec<SpecViolation>> {
+        let mut violations = Vec::new();
+
+        if let Some(name) = self.name() {
+            if !parser::is_valid_identifier(name) {
+                violations.push(SpecViolation::InvalidName {
+                    path: "name".into(),
+                    name: name.clone(),
+                });
+            }
+        }
+
+        let state_mutability_allowed = match self {
+            Self::Constructor(_) | Self::Fallback(_) => matches!(
+                self.state_mutability(),
+                Some(StateMutability::NonPayable | StateMutability::Payable)
+            ),
+            Self::Receive(item) => item.state_mutability == StateMutability::Payable,
+            Self::Function(_) | Self::Event(_) | Self::Error(_) => true,
+        };
+        if !state_mutability_allowed {
+            violations.push(SpecViolation::InvalidStateMutability(
+                self.state_mutability_or_default(),
+            ));
+        }
+
+        let mut path = String::new();
+        if let Self::Event(event) = self {
+            let indexed = event.inputs.iter().filter(|param| param.indexed).count();
+            if indexed > 3 + event.anonymous as usize {
+                violations.push(SpecViolation::TooManyIndexed(indexed));
+            }
+            for (i, param) in event.inputs.iter().enumerate() {
+                path.clear();
+                write!(path, "inputs[{i}]").unwrap();
+                spec_violations(
+                    &mut path,
+                    &param.name,
+                    &param.ty,
+                    &param.components,
+                    &mut violations,
+                );
+            }
+        }
+        let lists = [("inputs", self.inputs()), ("outputs", self.outputs())];
+        for (list, params) in lists {
+            for (i, param) in params.into_iter().flatten().enumerate() {
+                path.clear();
+                write!(path, "{list}[{i}]").unwrap();
+                spec_violations(
+                    &mut path,
+                    &param.name,
+                    &param.ty,
+                    &param.components,
+                    &mut violations,
+                );
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+impl Constructor {
+    /// Returns this constructor's pseudo-signature:
+    /// `constructor($($inputs),*)`.
+    ///
+    /// Constructors do not have a selector, so this is not used to encode any
+    /// call. It is only meant to display or identify the constructor, e.g. when
+    /// logging or hashing deployment ABIs.
+    #[inline]
+    pub fn signature(&self) -> String {
+        signature("constructor", &self.inputs, None)
+    }
 }
 
 impl Error {
+    /// Parses a Solidity error signature string: `$name($($inputs),*)`.
+    ///
+    /// See [`AbiItem::parse`] for how library-qualified struct parameters are
+    /// parsed; use [`parse_with_registry`](Self::parse_with_registry) to
+    /// resolve them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::Error;
+    /// let error = Error::parse("InsufficientBalance(uint256 available, uint256 required)")?;
+    /// assert_eq!(error.signature(), "InsufficientBalance(uint256,uint256)");
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn parse(s: &str) -> parser::Result<Self> {
+        let (name, inputs, rest) = parse_signature(s, parse_param)?;
+        if !rest.trim().is_empty() {
+            return Err(parser::Error::new(format_args!(
+                "unexpected trailing input in error signature: {rest:?}"
+            )))
+        }
+        Ok(Self {
+            name: name.into(),
+            inputs,
+        })
+    }
+
+    /// Parses a Solidity error signature string like [`parse`](Self::parse),
+    /// expanding parameters whose type is a struct name in `registry` into
+    /// tuples with the registered components.
+    ///
+    /// Expanded parameters keep the struct name as their internal type.
+    /// Library-qualified structs, e.g. `IERC20.Foo`, are looked up by their
+    /// qualified name first, and then by their unqualified name. Registered
+    /// components may themselves reference other registered structs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::{Error, Function};
+    /// # use std::collections::BTreeMap;
+    /// let point = Function::parse("f(uint256 x, uint256 y)")?.inputs;
+    /// let registry = BTreeMap::from([("Point".to_string(), point)]);
+    /// let error = Error::parse_with_registry("OutOfBounds(Point[] points)", &registry)?;
+    /// assert_eq!(error.signature(), "OutOfBounds((uint256,uint256)[])");
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn parse_with_registry(
+        s: &str,
+        registry: &BTreeMap<String, Vec<Param>>,
+    ) -> parser::Result<Self> {
+        let mut error = Self::parse(s)?;
+        resolve_structs(&mut error.inputs, registry, 0)?;
+        Ok(error)
+    }
+
     /// Computes this error's signature: `$name($($inputs),*)`.
     ///
     /// This is the preimage input used to [compute the
@@ -366,14 +1323,178 @@ impl Error {
         signature(&self.name, &self.inputs, None)
     }
 
+    /// Returns the UTF-8 bytes of this error's [signature](Self::signature),
+    /// i.e. the preimage of its selector.
+    #[inline]
+    pub fn signature_bytes(&self) -> Vec<u8> {
+        self.signature().into_bytes()
+    }
+
     /// Computes this error's selector: `keccak256(self.signature())[..4]`
     #[inline]
     pub fn selector(&self) -> Selector {
         selector(&self.signature())
     }
+
+    /// Returns this error's selector as a lowercase, `0x`-prefixed hex string,
+    /// e.g. `0xa9059cbb`.
+    #[inline]
+    pub fn selector_hex(&self) -> String {
+        alloy_primitives::hex::encode_prefixed(self.selector())
+    }
+
+    /// Returns this error's text signature as used by 4-byte selector
+    /// databases: no parameter names, no spaces, and tuples flattened into
+    /// their canonical component types.
+    ///
+    /// This is always equal to [`signature`](Self::signature).
+    #[inline]
+    pub fn db_signature(&self) -> String {
+        self.signature()
+    }
 }
 
 impl Function {
+    /// Parses a Solidity function signature string:
+    /// `$name($($inputs),*) $($modifiers)* $(returns ($($outputs),*))?`.
+    ///
+    /// The `external` and `public` visibilities are accepted and ignored.
+    /// The state mutability defaults to `nonpayable`.
+    ///
+    /// Library-qualified struct parameters have unknown components, which
+    /// makes the signature and selector invalid; see [`AbiItem::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::{Function, StateMutability};
+    /// let function = Function::parse("balanceOf(address owner) external view returns (uint256)")?;
+    /// assert_eq!(function.signature_full(), "balanceOf(address)(uint256)");
+    /// assert_eq!(function.state_mutability, StateMutability::View);
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn parse(s: &str) -> parser::Result<Self> {
+        let (name, inputs, rest) = parse_signature(s, parse_param)?;
+        let (state_mutability, outputs) = parse_function_modifiers(rest, true)?;
+        Ok(Self {
+            name: name.into(),
+            inputs,
+            outputs,
+            state_mutability,
+        })
+    }
+
+    /// Returns the names shared by more than one function in `items`, i.e.
+    /// the overloaded function names.
+    ///
+    /// Code generators can use this to know which names need to be
+    /// disambiguated. Items other than functions are ignored.
+    pub fn overloaded_names(items: &[AbiItem<'_>]) -> BTreeSet<String> {
+        let mut seen = BTreeSet::new();
+        let mut overloaded = BTreeSet::new();
+        for item in items {
+            if let AbiItem::Function(f) = item {
+                if !seen.insert(f.name.as_str()) {
+                    overloaded.insert(f.name.clone());
+                }
+            }
+        }
+        overloaded
+    }
+
+    /// Returns the functions and errors in `items` that share a selector, as
+    /// `(selector, function signature, error signature)` tuples sorted by
+    /// selector.
+    ///
+    /// Revert data cannot be told apart from a call's encoding by its
+    /// selector alone in this case, which makes decoding it ambiguous.
+    pub fn function_error_collisions(items: &[AbiItem<'_>]) -> Vec<(Selector, String, String)> {
+        let mut errors = BTreeMap::<Selector, Vec<&Error>>::new();
+        for item in items {
+            if let AbiItem::Error(e) = item {
+                errors.entry(e.selector()).or_default().push(e);
+            }
+        }
+        let mut collisions = Vec::new();
+        for item in items {
+            let AbiItem::Function(f) = item else { continue };
+            let selector = f.selector();
+            for e in errors.get(&selector).into_iter().flatten() {
+                collisions.push((selector, f.signature(), e.signature()));
+            }
+        }
+        collisions.sort_unstable();
+        collisions
+    }
+
+    /// Returns the byte offset of each input's head within the encoded
+    /// arguments, i.e. the calldata after the selector.
+    ///
+    /// As per the [ABI spec], the head of a dynamic input is a single 32-byte
+    /// word containing the offset of its data in the tail, while a static
+    /// input, including a static tuple or fixed-size array, is encoded in
+    /// place in the head with its full size. See
+    /// [`Param::encoded_size_bounds`].
+    ///
+    /// [ABI spec]: https://docs.soliditylang.org/en/latest/abi-spec.html#formal-specification-of-the-encoding
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::Function;
+    /// let f = Function::parse("foo(uint256 a, bytes b, (bool, address)[2] c, string d)")?;
+    /// assert_eq!(f.input_offsets(), [0, 32, 64, 192]);
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn input_offsets(&self) -> Vec<usize> {
+        let mut offset = 0;
+        self.inputs
+            .iter()
+            .map(|input| {
+                let current = offset;
+                let head_size = input.encoded_size_bounds().1.unwrap_or(32);
+                offset += head_size;
+                current
+            })
+            .collect()
+    }
+
+    /// True if calling this function may change the state, i.e. if it is
+    /// `payable` or `nonpayable`. Otherwise, it is `pure` or `view` and can be
+    /// executed with `eth_call`.
+    #[inline]
+    pub const fn is_state_changing(&self) -> bool {
+        matches!(
+            self.state_mutability,
+            StateMutability::Payable | StateMutability::NonPayable
+        )
+    }
+
+    /// Compares this function with `other`, its counterpart in a newer
+    /// version of the ABI, and returns the old and new state mutabilities if
+    /// they have the same selector but different state mutabilities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::{Function, StateMutability};
+    /// let old = Function::parse("balanceOf(address owner) view returns (uint256)")?;
+    /// let new = Function::parse("balanceOf(address account) returns (uint256)")?;
+    /// assert_eq!(
+    ///     old.mutability_changed(&new),
+    ///     Some((StateMutability::View, StateMutability::NonPayable))
+    /// );
+    /// assert_eq!(old.mutability_changed(&old), None);
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn mutability_changed(
+        &self,
+        other: &Function,
+    ) -> Option<(StateMutability, StateMutability)> {
+        (self.state_mutability != other.state_mutability && self.selector() == other.selector())
+            .then_some((self.state_mutability, other.state_mutability))
+    }
+
     /// Returns this function's signature: `$name($($inputs),*)`.
     ///
     /// This is the preimage input used to [compute the
@@ -383,6 +1504,13 @@ impl Function {
         signature(&self.name, &self.inputs, None)
     }
 
+    /// Returns the UTF-8 bytes of this function's [signature](Self::signature),
+    /// i.e. the preimage of its selector.
+    #[inline]
+    pub fn signature_bytes(&self) -> Vec<u8> {
+        self.signature().into_bytes()
+    }
+
     /// Returns this function's full signature:
     /// `$name($($inputs),*)($(outputs),*)`.
     ///
@@ -393,14 +1521,197 @@ impl Function {
         signature(&self.name, &self.inputs, Some(&self.outputs))
     }
 
+    /// Returns this function's input types as a tuple signature:
+    /// `($($inputs),*)`.
+    ///
+    /// This is the same as [`signature`](Self::signature), without the name.
+    #[inline]
+    pub fn inputs_signature(&self) -> String {
+        signature("", &self.inputs, None)
+    }
+
+    /// Returns this function's signature, formatted with the given options.
+    ///
+    /// With the default options, this is the same as
+    /// [`signature`](Self::signature). Other options can be used to build the
+    /// selector preimages of non-standard schemes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::{Function, SignatureOptions};
+    /// let f = Function::parse("transfer(address to, uint256 amount)")?;
+    /// let opts = SignatureOptions { separator: ", ", include_names: true };
+    /// assert_eq!(f.signature_with_options(opts), "transfer(address to, uint256 amount)");
+    /// assert_eq!(f.signature_with_options(Default::default()), f.signature());
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn signature_with_options(&self, opts: SignatureOptions<'_>) -> String {
+        let mut s = String::with_capacity(self.name.len() + 2 + self.inputs.len() * 32);
+        s.push_str(&self.name);
+        signature_with_options_raw(&self.inputs, &opts, &mut s);
+        s
+    }
+
     /// Computes this error's selector: `keccak256(self.signature())[..4]`
     #[inline]
     pub fn selector(&self) -> Selector {
         selector(&self.signature())
     }
+
+    /// Returns this function's selector as a lowercase, `0x`-prefixed hex
+    /// string, e.g. `0xa9059cbb`.
+    #[inline]
+    pub fn selector_hex(&self) -> String {
+        alloy_primitives::hex::encode_prefixed(self.selector())
+    }
+
+    /// Formats this function as a human-readable signature:
+    /// `function $name($($inputs),*) $($state_mutability)? $(returns
+    /// ($($outputs),*))?`.
+    ///
+    /// The output can be parsed back with [`AbiItem::parse`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the function or any of its parameters has a name that is not
+    /// a valid Solidity identifier, or a parameter type that is not a valid
+    /// type string. This can happen with ABIs constructed from untrusted
+    /// input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::Function;
+    /// let function = Function::parse("balanceOf(address owner) external view returns (uint256)")?;
+    /// assert_eq!(
+    ///     function.try_to_human_readable()?,
+    ///     "function balanceOf(address owner) view returns (uint256)"
+    /// );
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn try_to_human_readable(&self) -> parser::Result<String> {
+        validate_name(&self.name)?;
+        let mut out = String::new();
+        out.push_str("function ");
+        out.push_str(&self.name);
+        human_readable_params(&self.inputs, &mut out)?;
+        if let Some(state_mutability) = self.state_mutability.as_str() {
+            out.push(' ');
+            out.push_str(state_mutability);
+        }
+        if !self.outputs.is_empty() {
+            out.push_str(" returns ");
+            human_readable_params(&self.outputs, &mut out)?;
+        }
+        Ok(out)
+    }
+
+    /// Serializes this function as a JSON ABI fragment, e.g.:
+    ///
+    /// ```json
+    /// {"type":"function","name":"foo","inputs":[],"outputs":[],"stateMutability":"view"}
+    /// ```
+    ///
+    /// This is the same as serializing the function with [`serde_json`], but
+    /// the field order is guaranteed to be `type`, `name`, `inputs`,
+    /// `outputs` and `stateMutability`, with parameter fields in the order
+    /// `name`, `type`, `internalType` and `components`. The output is compact
+    /// and can be hashed reproducibly.
+    #[cfg(feature = "serde_json")]
+    pub fn to_fragment_json(&self) -> String {
+        serde_json::to_string(self).expect("serializing a function cannot fail")
+    }
+
+    /// Returns this function's text signature as used by 4-byte selector
+    /// databases: no parameter names, no spaces, and tuples flattened into
+    /// their canonical component types.
+    ///
+    /// This is always equal to [`signature`](Self::signature).
+    #[inline]
+    pub fn db_signature(&self) -> String {
+        self.signature()
+    }
 }
 
 impl Event {
+    /// Parses a Solidity event signature string:
+    /// `$(event)? $name($($inputs),*) $(anonymous)?`.
+    ///
+    /// The leading `event` keyword is optional, so declarations can be copied
+    /// from Solidity sources as-is.
+    ///
+    /// Library-qualified struct parameters have unknown components, which
+    /// makes the signature and selector invalid; see [`AbiItem::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::Event;
+    /// let event = Event::parse("Transfer(address indexed from, address indexed to, uint256 value)")?;
+    /// assert_eq!(event.signature(), "Transfer(address,address,uint256)");
+    /// assert!(event.inputs[0].indexed);
+    /// assert_eq!(Event::parse("event Transfer(address indexed from, address indexed to, uint256 value)")?, event);
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn parse(s: &str) -> parser::Result<Self> {
+        let s = s.trim_start();
+        let s = s
+            .strip_prefix("event")
+            .filter(|rest| rest.starts_with(char::is_whitespace))
+            .unwrap_or(s);
+        let (name, inputs, rest) = parse_signature(s, parse_event_param)?;
+        let anonymous = match rest.trim() {
+            "" => false,
+            "anonymous" => true,
+            rest => {
+                return Err(parser::Error::new(format_args!(
+                    "unexpected trailing input in event signature: {rest:?}"
+                )))
+            }
+        };
+        Ok(Self {
+            name: name.into(),
+            inputs,
+            anonymous,
+        })
+    }
+
+    /// Parses a candidate event signature, as in [`parse`](Self::parse), and
+    /// returns the event only if its [topic](Self::selector) is `topic0`.
+    ///
+    /// This can be used to decode a log given its first topic and a list of
+    /// candidate signatures. Anonymous events are rejected, since they do not
+    /// emit their topic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::Event;
+    /// # use alloy_primitives::b256;
+    /// let topic0 = b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+    /// let event = Event::verify_signature(topic0, "Transfer(address indexed from, address indexed to, uint256 value)")?;
+    /// assert_eq!(event.name, "Transfer");
+    /// assert!(Event::verify_signature(topic0, "Approval(address,address,uint256)").is_err());
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn verify_signature(topic0: B256, signature: &str) -> parser::Result<Self> {
+        let event = Self::parse(signature)?;
+        if event.anonymous {
+            return Err(parser::Error::new(format_args!(
+                "anonymous event does not match any topic: {signature:?}"
+            )))
+        }
+        let selector = event.selector();
+        if selector != topic0 {
+            return Err(parser::Error::new(format_args!(
+                "event signature {:?} has topic {selector}, expected {topic0}",
+                event.signature()
+            )))
+        }
+        Ok(event)
+    }
+
     /// Returns this event's signature: `$name($($inputs),*)`.
     ///
     /// This is the preimage input used to [compute the
@@ -410,9 +1721,246 @@ impl Event {
         event_signature(&self.name, &self.inputs)
     }
 
+    /// Returns the UTF-8 bytes of this event's [signature](Self::signature),
+    /// i.e. the preimage of its selector.
+    #[inline]
+    pub fn signature_bytes(&self) -> Vec<u8> {
+        self.signature().into_bytes()
+    }
+
     /// Computes this event's selector: `keccak256(self.signature())`
     #[inline]
     pub fn selector(&self) -> B256 {
         keccak256(self.signature().as_bytes())
     }
+
+    /// Returns a copy of this event with its indexed parameters moved before
+    /// the non-indexed ones, preserving the relative order within each group.
+    ///
+    /// This is only meant for display and analysis: the parameter order is
+    /// part of the event's [signature](Self::signature), so the returned event
+    /// may have a different selector, and must not be used to decode logs
+    /// emitted with the original one.
+    pub fn with_indexed_first(&self) -> Self {
+        let (mut inputs, non_indexed): (Vec<_>, Vec<_>) =
+            self.inputs.iter().cloned().partition(|param| param.indexed);
+        inputs.extend(non_indexed);
+        Self {
+            name: self.name.clone(),
+            inputs,
+            anonymous: self.anonymous,
+        }
+    }
+
+    /// Returns the first topic of this event's logs, which is its
+    /// [selector](Self::selector).
+    ///
+    /// Note that anonymous events do not emit this topic.
+    #[inline]
+    pub fn topic0_only(&self) -> B256 {
+        self.selector()
+    }
+
+    /// Returns `true` if the first topic of the event's logs is its
+    /// signature's hash, i.e. if the event is not anonymous.
+    ///
+    /// The indexed parameters of anonymous events start at the first topic
+    /// instead, so they can have up to 4 indexed parameters rather than 3.
+    #[inline]
+    pub const fn topic0_is_signature(&self) -> bool {
+        !self.anonymous
+    }
+
+    /// Returns the log topics occupied by this event's indexed parameters, in
+    /// topic order.
+    ///
+    /// Topics start at index 1, after the selector, or at 0 for anonymous
+    /// events.
+    pub fn topic_layout(&self) -> Vec<TopicSlot> {
+        let first = self.topic0_is_signature() as usize;
+        self.inputs
+            .iter()
+            .enumerate()
+            .filter(|(_, param)| param.indexed)
+            .enumerate()
+            .map(|(i, (param_index, param))| TopicSlot {
+                topic: first + i,
+                param: param_index,
+                hashed: param.is_hashed_when_indexed(),
+            })
+            .collect()
+    }
+
+    /// Returns the canonical type of each indexed parameter, in topic order,
+    /// along with whether its topic holds the `keccak256` hash of its encoding
+    /// rather than its value.
+    ///
+    /// This describes how to encode values into the topics of a log filter.
+    /// See [`EventParam::is_hashed_when_indexed`].
+    pub fn topic_filter_spec(&self) -> Vec<(String, bool)> {
+        self.inputs
+            .iter()
+            .filter(|param| param.indexed)
+            .map(|param| {
+                (
+                    param.selector_type().into_owned(),
+                    param.is_hashed_when_indexed(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns `true` if a log with the given topics and data length could
+    /// have been emitted by this event.
+    ///
+    /// This checks that the first topic is the event's selector, unless the
+    /// event is anonymous, that there is one topic per indexed parameter, and
+    /// that the data length is a multiple of 32. It is meant as a cheap
+    /// pre-filter before decoding the log, which may still fail.
+    pub fn matches_log(&self, topics: &[B256], data_len: usize) -> bool {
+        let indexed = self.inputs.iter().filter(|param| param.indexed).count();
+        let selector_matches = self.anonymous || topics.first() == Some(&self.selector());
+        selector_matches && topics.len() == indexed + !self.anonymous as usize && data_len % 32 == 0
+    }
+
+    /// Returns the canonical types of the indexed parameters, in declaration
+    /// order. These are the types of the log topics following the selector.
+    ///
+    /// Note that indexed parameters of dynamic types (`bytes`, `string`,
+    /// arrays and tuples) are not stored directly: their topic contains the
+    /// `keccak256` hash of their encoding instead.
+    #[inline]
+    pub fn indexed_types(&self) -> Vec<String> {
+        self.inputs
+            .iter()
+            .filter(|param| param.indexed)
+            .map(|param| param.selector_type().into_owned())
+            .collect()
+    }
+
+    /// Returns the non-indexed parameters, in declaration order. These are
+    /// the parameters ABI-encoded as a tuple in the log data.
+    #[inline]
+    pub fn data_params(&self) -> Vec<&EventParam> {
+        self.inputs.iter().filter(|param| !param.indexed).collect()
+    }
+}
+
+/// A log topic occupied by an indexed event parameter.
+///
+/// Returned by [`Event::topic_layout`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TopicSlot {
+    /// The index of the topic in the log.
+    pub topic: usize,
+    /// The index of the parameter in the event's inputs.
+    pub param: usize,
+    /// Whether the topic contains the `keccak256` hash of the parameter's
+    /// encoding rather than its value, because the parameter is of a dynamic
+    /// type.
+    pub hashed: bool,
+}
+
+/// Options for formatting a signature.
+///
+/// Used by [`Function::signature_with_options`]. The default options produce
+/// the standard selector preimage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SignatureOptions<'a> {
+    /// The separator between parameters. Defaults to `,`.
+    pub separator: &'a str,
+    /// Whether to include the names of the parameters, after their types.
+    /// Defaults to `false`.
+    pub include_names: bool,
+}
+
+impl Default for SignatureOptions<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            separator: ",",
+            include_names: false,
+        }
+    }
+}
+
+/// A violation of the Solidity ABI JSON specification.
+///
+/// Returned by [`AbiItem::validate_spec`]. Paths point to the offending
+/// parameter, e.g. `inputs[1].components[0]`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SpecViolation {
+    /// A name is not a valid Solidity identifier.
+    InvalidName {
+        /// The path to the item's name or to the parameter.
+        path: String,
+        /// The invalid name.
+        name: String,
+    },
+    /// A parameter's type is not a valid Solidity type.
+    InvalidType {
+        /// The path to the parameter.
+        path: String,
+        /// The invalid type.
+        ty: String,
+    },
+    /// A parameter has a `tuple` type but no components.
+    TupleWithoutComponents {
+        /// The path to the parameter.
+        path: String,
+    },
+    /// A parameter has components but its type is not `tuple`.
+    ComponentsOnNonTuple {
+        /// The path to the parameter.
+        path: String,
+    },
+    /// The state mutability is not allowed for the item's kind: constructors
+    /// and fallbacks must be `nonpayable` or `payable`, and receives must be
+    /// `payable`.
+    InvalidStateMutability(StateMutability),
+    /// An event has more than 3 indexed parameters, or more than 4 if it is
+    /// anonymous.
+    TooManyIndexed(usize),
+}
+
+impl fmt::Display for SpecViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName { path, name } => write!(f, "invalid name at {path}: {name:?}"),
+            Self::InvalidType { path, ty } => write!(f, "invalid type at {path}: {ty:?}"),
+            Self::TupleWithoutComponents { path } => {
+                write!(f, "tuple type without components at {path}")
+            }
+            Self::ComponentsOnNonTuple { path } => {
+                write!(f, "non-tuple type with components at {path}")
+            }
+            Self::InvalidStateMutability(state_mutability) => write!(
+                f,
+                "invalid state mutability: {}",
+                state_mutability.as_str().unwrap_or("nonpayable")
+            ),
+            Self::TooManyIndexed(count) => write!(f, "too many indexed parameters: {count}"),
+        }
+    }
+}
+
+/// An advisory warning about an ABI item.
+///
+/// Returned by [`AbiItem::lint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LintWarning {
+    /// A `nonpayable` function looks like a getter: its name starts with
+    /// `get`, and it has outputs but no inputs. It was likely meant to be
+    /// `view`.
+    SuspiciousMutability,
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SuspiciousMutability => {
+                f.write_str("getter-like function is nonpayable, it may be meant to be view")
+            }
+        }
+    }
 }
@@ -34,11 +34,17 @@ use serde::{Deserialize, Serialize};
 mod abi;
 pub use abi::{ContractObject, IntoItems, Items, JsonAbi};
 
+mod dispatch;
+pub use dispatch::AbiDispatch;
+
 mod item;
-pub use item::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
+pub use item::{
+    AbiItem, AbiItemKind, Constructor, Error, Event, Fallback, Function, LintWarning, Receive,
+    SignatureOptions, SpecViolation, TopicSlot,
+};
 
 mod param;
-pub use param::{EventParam, Param};
+pub use param::{EventParam, Param, TypeTree};
 
 mod internal_type;
 pub use internal_type::InternalType;
@@ -75,3 +81,97 @@ impl StateMutability {
         }
     }
 }
+
+impl TryFrom<&str> for StateMutability {
+    type Error = parser::Error;
+
+    /// Parses a state mutability, case-insensitively.
+    ///
+    /// Accepts `pure`, `view`, `nonpayable` and `payable`, as well as the
+    /// aliases `constant` for `view`, and `non-payable` and `mutable` for
+    /// `nonpayable`.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        const VARIANTS: &[(&str, StateMutability)] = &[
+            ("pure", StateMutability::Pure),
+            ("view", StateMutability::View),
+            ("constant", StateMutability::View),
+            ("nonpayable", StateMutability::NonPayable),
+            ("non-payable", StateMutability::NonPayable),
+            ("mutable", StateMutability::NonPayable),
+            ("payable", StateMutability::Payable),
+        ];
+        VARIANTS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+            .map(|&(_, state_mutability)| state_mutability)
+            .ok_or_else(|| {
+                parser::Error::new(format_args!(
+                    "invalid state mutability: {s:?}, expected one of `pure`, `view`, \
+                     `nonpayable` or `payable`"
+                ))
+            })
+    }
+}
+
+impl core::str::FromStr for StateMutability {
+    type Err = parser::Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+// Checks that the parsing and formatting APIs are available without `std`.
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_tests {
+    use super::*;
+    use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+    #[test]
+    fn parse_and_format() {
+        let f = Function::parse("transfer(address to, uint256 amount) returns (bool)").unwrap();
+        assert_eq!(f.signature(), "transfer(address,uint256)");
+        assert_eq!(f.inputs_signature(), "(address,uint256)");
+        assert_eq!(f.selector_hex(), "0xa9059cbb");
+        assert_eq!(
+            f.try_to_human_readable().unwrap(),
+            "function transfer(address to, uint256 amount) returns (bool)"
+        );
+
+        let e =
+            Event::parse("Transfer(address indexed from, address indexed to, uint256)").unwrap();
+        assert_eq!(e.signature(), "Transfer(address,address,uint256)");
+        assert!(Event::verify_signature(e.selector(), "Transfer(address,address,uint256)").is_ok());
+
+        let registry = BTreeMap::from([("Point".to_string(), f.inputs.clone())]);
+        let err = Error::parse_with_registry("OutOfBounds(Point p)", &registry).unwrap();
+        assert_eq!(err.signature(), "OutOfBounds((address,uint256))");
+
+        let items: Vec<_> = [
+            "constructor()",
+            "receive() external payable",
+            "error Unauthorized()",
+        ]
+        .into_iter()
+        .map(|s| AbiItem::parse(s).unwrap())
+        .collect();
+        assert_eq!(
+            AbiItem::fmt_human_readable_abi(&items).unwrap(),
+            "constructor()\nreceive() external payable\nerror Unauthorized()\n"
+        );
+        assert_eq!(
+            items[1].to_solidity().unwrap(),
+            "receive() external payable;"
+        );
+
+        let abi: JsonAbi = items.into_iter().chain([f.into(), e.into()]).collect();
+        assert_eq!(abi.selector_table().len(), 2);
+        let sol = abi.to_sol("Token");
+        assert!(sol.starts_with("interface Token{"), "{sol}");
+        assert!(
+            sol.contains("function transfer(address to, uint256 amount) external returns (bool);"),
+            "{sol}"
+        );
+    }
+}
@@ -1,11 +1,17 @@
-use crate::{internal_type::BorrowedInternalType, utils::validate_identifier, InternalType};
+use crate::{
+    internal_type::BorrowedInternalType,
+    utils::{canonical_root_type, validate_identifier},
+    InternalType,
+};
 use alloc::{
     borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::BTreeMap,
     string::String,
     vec::Vec,
 };
-use alloy_sol_type_parser::TypeSpecifier;
-use core::fmt;
+use alloy_sol_type_parser::{self as parser, TypeSpecifier, TypeStem};
+use core::fmt::{self, Write};
 use serde::{de::Unexpected, Deserialize, Deserializer, Serialize, Serializer};
 
 /// JSON specification of a parameter.
@@ -13,6 +19,11 @@ use serde::{de::Unexpected, Deserialize, Deserializer, Serialize, Serializer};
 /// Parameters are the inputs and outputs of [Function]s, and the fields of
 /// [Error]s.
 ///
+/// The derived [`PartialEq`] and [`Hash`] implementations recurse through the
+/// components, so deserialization rejects parameters nested deeper than
+/// [`MAX_NESTING_DEPTH`](Self::MAX_NESTING_DEPTH) to keep them from
+/// overflowing the stack.
+///
 /// [Function]: crate::Function
 /// [Error]: crate::Error
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
@@ -74,12 +85,110 @@ impl Serialize for Param {
 }
 
 impl Param {
+    /// The maximum nesting depth of the components of a deserialized
+    /// parameter, e.g. 1 for `(uint256,bool)` and 2 for `((uint256),bool)`.
+    pub const MAX_NESTING_DEPTH: usize = 32;
+
+    /// Creates a new parameter from a parsed type specifier.
+    ///
+    /// Tuple types, e.g. `(uint256,bool)[2]`, become `tuple` types, e.g.
+    /// `tuple[2]`, with unnamed components built recursively from the tuple's
+    /// types. Aliases are normalized to their canonical form, e.g. `uint` to
+    /// `uint256`, as when parsing a human-readable signature.
+    ///
+    /// # Errors
+    ///
+    /// Errors if a sized type has an invalid size, e.g. `uint7`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::Param;
+    /// # use alloy_sol_type_parser::TypeSpecifier;
+    /// let spec = TypeSpecifier::parse("(uint256,bool[])[2]")?;
+    /// let param = Param::from_type_specifier("foo", &spec)?;
+    /// assert_eq!(param.ty, "tuple[2]");
+    /// assert_eq!(param.components[1].ty, "bool[]");
+    /// assert_eq!(param.selector_type(), "(uint256,bool[])[2]");
+    /// # Ok::<_, alloy_sol_type_parser::Error>(())
+    /// ```
+    pub fn from_type_specifier(name: &str, spec: &TypeSpecifier<'_>) -> parser::Result<Self> {
+        let (mut ty, components) = match &spec.stem {
+            TypeStem::Root(root) => (String::from(canonical_root_type(root.span())?), Vec::new()),
+            TypeStem::Tuple(tuple) => (
+                String::from("tuple"),
+                tuple
+                    .types
+                    .iter()
+                    .map(|ty| Self::from_type_specifier("", ty))
+                    .collect::<parser::Result<_>>()?,
+            ),
+        };
+        for size in &spec.sizes {
+            match size {
+                Some(size) => write!(ty, "[{size}]").unwrap(),
+                None => ty.push_str("[]"),
+            }
+        }
+        Ok(Self {
+            name: name.into(),
+            ty,
+            components,
+            internal_type: None,
+        })
+    }
+
+    /// Trims whitespace around the type of the parameter and its components,
+    /// and validates them.
+    ///
+    /// Deserialized and parsed parameters always have normalized types, but
+    /// parameters built manually may not, in which case their signatures would
+    /// be invalid. Errors if a type is not a valid type specifier, or if a
+    /// parameter with components is not a tuple.
+    pub fn normalize_type(&mut self) -> parser::Result<()> {
+        let trimmed = self.ty.trim();
+        if trimmed.len() != self.ty.len() {
+            self.ty = trimmed.into();
+        }
+        let spec = TypeSpecifier::parse(&self.ty)?;
+        if !self.components.is_empty() && spec.stem.span() != "tuple" {
+            return Err(parser::Error::new(format_args!(
+                "non-tuple type with components: {:?}",
+                self.ty
+            )))
+        }
+        self.components
+            .iter_mut()
+            .try_for_each(Self::normalize_type)
+    }
+
     /// The internal type of the parameter.
     #[inline]
     pub const fn internal_type(&self) -> Option<&InternalType> {
         self.internal_type.as_ref()
     }
 
+    /// The direct components of the parameter, if it is a tuple. Empty
+    /// otherwise.
+    #[inline]
+    pub fn components(&self) -> &[Param] {
+        &self.components
+    }
+
+    /// Returns a mutable reference to the direct components of the
+    /// parameter.
+    #[inline]
+    pub fn components_mut(&mut self) -> &mut [Param] {
+        &mut self.components
+    }
+
+    /// How deeply tuples are nested within this parameter: 0 for a scalar, 1
+    /// for a tuple of scalars, and so on.
+    #[inline]
+    pub fn nesting_depth(&self) -> usize {
+        nesting_depth(&self.components)
+    }
+
     /// True if the parameter is a UDT (user-defined type).
     ///
     /// A UDT will have
@@ -159,6 +268,54 @@ impl Param {
         self.internal_type().and_then(|ty| ty.contract_specifier())
     }
 
+    /// Fills in the components of tuple parameters that have none, looking up
+    /// their struct name in `registry`. Parameters that already have
+    /// components are resolved recursively.
+    ///
+    /// The struct name is taken from the `internal_type`, without the
+    /// contract qualifier or array sizes: `struct Pairing.G1Point[]` is looked
+    /// up as `G1Point`. Parameters that are not structs, or whose struct is
+    /// not in the registry, are left unchanged.
+    pub fn resolve_components(&mut self, registry: &BTreeMap<String, Vec<Param>>) {
+        self.resolve_components_inner(registry, &mut Vec::new());
+    }
+
+    fn resolve_components_inner<'r>(
+        &mut self,
+        registry: &'r BTreeMap<String, Vec<Param>>,
+        stack: &mut Vec<&'r str>,
+    ) {
+        if self.components.is_empty() && self.ty.starts_with("tuple") {
+            let entry = self
+                .struct_specifier()
+                .and_then(|spec| registry.get_key_value(spec.stem.span()));
+            if let Some((name, components)) = entry {
+                // skip recursive definitions
+                if !stack.contains(&name.as_str()) {
+                    stack.push(name);
+                    self.components = components.clone();
+                    for component in &mut self.components {
+                        component.resolve_components_inner(registry, stack);
+                    }
+                    stack.pop();
+                }
+            }
+        } else {
+            for component in &mut self.components {
+                component.resolve_components_inner(registry, stack);
+            }
+        }
+    }
+
+    /// True if the parameters are ABI-equivalent: their names, types and
+    /// components are equal, ignoring the informational `internal_type`.
+    pub fn abi_eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.ty == other.ty
+            && self.components.len() == other.components.len()
+            && core::iter::zip(&self.components, &other.components).all(|(a, b)| a.abi_eq(b))
+    }
+
     /// True if the type is simple
     #[inline]
     pub fn is_simple_type(&self) -> bool {
@@ -176,15 +333,7 @@ impl Param {
     /// This is used to encode the preimage of a function or error selector.
     #[inline]
     pub fn selector_type_raw(&self, s: &mut String) {
-        if self.components.is_empty() {
-            s.push_str(&self.ty);
-        } else {
-            crate::utils::signature_raw(&self.components, s);
-            // checked during deserialization, but might be invalid from a user
-            if let Some(suffix) = self.ty.strip_prefix("tuple") {
-                s.push_str(suffix);
-            }
-        }
+        crate::utils::selector_type_raw(&self.ty, &self.components, s);
     }
 
     /// Returns the canonical type of this parameter.
@@ -201,6 +350,196 @@ impl Param {
         }
     }
 
+    /// Returns the type of this parameter with all tuple components inlined,
+    /// e.g. `(address,(uint256,bool)[2])[]`.
+    ///
+    /// This is the same as [`selector_type`](Self::selector_type), except that
+    /// tuples without components are also inlined, as `()`, instead of being
+    /// left as `tuple`. The output can therefore always be parsed without the
+    /// components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::Function;
+    /// let f = Function::parse("foo((address a, (uint256 b, bool c)[2] d)[] e)")?;
+    /// assert_eq!(f.inputs[0].to_inline_type(), "(address,(uint256,bool)[2])[]");
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn to_inline_type(&self) -> String {
+        let mut s = String::with_capacity(self.ty.len() + self.components.len() * 32);
+        self.inline_type_raw(&mut s);
+        s
+    }
+
+    /// Serializes this parameter as a JSON ABI parameter object, with the
+    /// `name`, `type`, `internalType` and `components` fields.
+    ///
+    /// `internalType` is omitted if not set, and `components` if empty, as
+    /// when serializing the whole item.
+    #[cfg(feature = "serde_json")]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("serializing a parameter cannot fail")
+    }
+
+    fn inline_type_raw(&self, s: &mut String) {
+        match self.ty.strip_prefix("tuple") {
+            Some(suffix) if suffix.is_empty() || suffix.starts_with('[') => {
+                s.push('(');
+                for (i, component) in self.components.iter().enumerate() {
+                    if i > 0 {
+                        s.push(',');
+                    }
+                    component.inline_type_raw(s);
+                }
+                s.push(')');
+                s.push_str(suffix);
+            }
+            _ => self.selector_type_raw(s),
+        }
+    }
+
+    /// Returns the canonical type of this parameter, like
+    /// [`selector_type`](Self::selector_type), but with the names of the
+    /// tuple components, e.g. `(address token, uint256 amount)[]`.
+    ///
+    /// The name of the parameter itself is not included.
+    pub fn canonical_with_names(&self) -> String {
+        let mut s = String::with_capacity(self.components.len() * 32);
+        self.canonical_with_names_raw(&mut s);
+        s
+    }
+
+    fn canonical_with_names_raw(&self, s: &mut String) {
+        if self.components.is_empty() {
+            s.push_str(&self.ty);
+            return
+        }
+        s.push('(');
+        for (i, component) in self.components.iter().enumerate() {
+            if i > 0 {
+                s.push_str(", ");
+            }
+            component.canonical_with_names_raw(s);
+            if !component.name.is_empty() {
+                s.push(' ');
+                s.push_str(&component.name);
+            }
+        }
+        s.push(')');
+        if let Some(suffix) = self.ty.strip_prefix("tuple") {
+            s.push_str(suffix);
+        }
+    }
+
+    /// Returns an estimate of the heap memory used by the parameter.
+    pub(crate) fn heap_size(&self) -> usize {
+        self.name.capacity()
+            + self.ty.capacity()
+            + self
+                .internal_type
+                .as_ref()
+                .map_or(0, InternalType::heap_size)
+            + crate::utils::params_heap_size(&self.components, self.components.capacity())
+    }
+
+    /// Returns a multi-line rendering of this parameter's type and name, with
+    /// one line per component indented below its tuple. Meant for human
+    /// inspection of complex parameters; use
+    /// [`selector_type`](Self::selector_type) for the canonical type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::Function;
+    /// let f = Function::parse("foo((uint256 a, (bool b, bytes c)[] d) order)")?;
+    /// assert_eq!(
+    ///     f.inputs[0].to_pretty_type(),
+    ///     "\
+    /// tuple order
+    ///     uint256 a
+    ///     tuple[] d
+    ///         bool b
+    ///         bytes c"
+    /// );
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn to_pretty_type(&self) -> String {
+        let mut out = String::new();
+        self.pretty_type_raw(0, &mut out);
+        out
+    }
+
+    fn pretty_type_raw(&self, depth: usize, out: &mut String) {
+        if depth > 0 {
+            out.push('\n');
+        }
+        for _ in 0..depth {
+            out.push_str("    ");
+        }
+        out.push_str(&self.ty);
+        if !self.name.is_empty() {
+            out.push(' ');
+            out.push_str(&self.name);
+        }
+        for component in &self.components {
+            component.pretty_type_raw(depth + 1, out);
+        }
+    }
+
+    /// Returns the bounds of the ABI-encoded size of a value of this
+    /// parameter's type, in bytes: `(min, max)`.
+    ///
+    /// `max` is `None` if the type is dynamic, i.e. if it contains `bytes`,
+    /// `string` or a dynamic array, since its size is then unbounded. For
+    /// static types, including fixed-size arrays and tuples of static types,
+    /// the size is exact and `min == max`.
+    ///
+    /// Dynamic values nested in a tuple or fixed-size array also account for
+    /// their 32-byte offset. Invalid types return `(0, None)`.
+    pub fn encoded_size_bounds(&self) -> (usize, Option<usize>) {
+        let Ok(spec) = TypeSpecifier::parse(&self.ty) else {
+            return (0, None)
+        };
+        let (mut min, mut is_static) = match spec.stem.span() {
+            "tuple" => {
+                let mut min = 0usize;
+                let mut is_static = true;
+                for component in &self.components {
+                    let (c_min, c_max) = component.encoded_size_bounds();
+                    if c_max.is_none() {
+                        // offset word
+                        min = min.saturating_add(32);
+                        is_static = false;
+                    }
+                    min = min.saturating_add(c_min);
+                }
+                (min, is_static)
+            }
+            // length word
+            "bytes" | "string" => (32, false),
+            _ => (32, true),
+        };
+        for size in &spec.sizes {
+            match size {
+                Some(len) => {
+                    let element = if is_static {
+                        min
+                    } else {
+                        min.saturating_add(32)
+                    };
+                    min = element.saturating_mul(len.get());
+                }
+                // length word, with no elements
+                None => {
+                    min = 32;
+                    is_static = false;
+                }
+            }
+        }
+        (min, is_static.then_some(min))
+    }
+
     #[inline]
     fn borrowed_internal_type(&self) -> Option<BorrowedInternalType<'_>> {
         self.internal_type().as_ref().map(|it| it.as_borrowed())
@@ -261,20 +600,15 @@ impl fmt::Display for EventParam {
 impl<'de> Deserialize<'de> for EventParam {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         BorrowedParam::deserialize(deserializer).and_then(|inner| {
-            if let Some(indexed) = inner.indexed {
-                inner.validate_fields()?;
-                Ok(Self {
-                    name: inner.name.to_owned(),
-                    ty: inner.ty.to_owned(),
-                    indexed,
-                    internal_type: inner.internal_type.map(Into::into),
-                    components: inner.components.into_owned(),
-                })
-            } else {
-                Err(serde::de::Error::custom(
-                    "indexed is required in event params",
-                ))
-            }
+            inner.validate_fields()?;
+            Ok(Self {
+                name: inner.name.to_owned(),
+                ty: inner.ty.to_owned(),
+                // not indexed if omitted
+                indexed: inner.indexed.unwrap_or(false),
+                internal_type: inner.internal_type.map(Into::into),
+                components: inner.components.into_owned(),
+            })
         })
     }
 }
@@ -293,6 +627,20 @@ impl EventParam {
         self.internal_type.as_ref()
     }
 
+    /// The direct components of the parameter, if it is a tuple. Empty
+    /// otherwise.
+    #[inline]
+    pub fn components(&self) -> &[Param] {
+        &self.components
+    }
+
+    /// Returns a mutable reference to the direct components of the
+    /// parameter.
+    #[inline]
+    pub fn components_mut(&mut self) -> &mut [Param] {
+        &mut self.components
+    }
+
     /// True if the parameter is a UDT (user-defined type).
     ///
     /// A UDT will have
@@ -372,6 +720,17 @@ impl EventParam {
         self.internal_type().and_then(|ty| ty.contract_specifier())
     }
 
+    /// True if the parameters are ABI-equivalent: their names, types,
+    /// `indexed` flags and components are equal, ignoring the informational
+    /// `internal_type`.
+    pub fn abi_eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.ty == other.ty
+            && self.indexed == other.indexed
+            && self.components.len() == other.components.len()
+            && core::iter::zip(&self.components, &other.components).all(|(a, b)| a.abi_eq(b))
+    }
+
     /// True if the type is simple
     #[inline]
     pub fn is_simple_type(&self) -> bool {
@@ -384,20 +743,24 @@ impl EventParam {
         !self.components.is_empty()
     }
 
+    /// True if the parameter's topic holds the `keccak256` hash of its
+    /// encoding rather than its value when it is indexed.
+    ///
+    /// This is the case for dynamic and reference types: `bytes`, `string`,
+    /// arrays and tuples.
+    #[inline]
+    pub fn is_hashed_when_indexed(&self) -> bool {
+        self.is_complex_type()
+            || self.ty.ends_with(']')
+            || matches!(self.ty.as_str(), "bytes" | "string" | "tuple")
+    }
+
     /// Formats the canonical type of this parameter into the given string.
     ///
     /// This is used to encode the preimage of the event selector.
     #[inline]
     pub fn selector_type_raw(&self, s: &mut String) {
-        if self.components.is_empty() {
-            s.push_str(&self.ty);
-        } else {
-            crate::utils::signature_raw(&self.components, s);
-            // checked during deserialization, but might be invalid from a user
-            if let Some(suffix) = self.ty.strip_prefix("tuple") {
-                s.push_str(suffix);
-            }
-        }
+        crate::utils::selector_type_raw(&self.ty, &self.components, s);
     }
 
     /// Returns the canonical type of this parameter.
@@ -414,6 +777,17 @@ impl EventParam {
         }
     }
 
+    /// Returns an estimate of the heap memory used by the parameter.
+    pub(crate) fn heap_size(&self) -> usize {
+        self.name.capacity()
+            + self.ty.capacity()
+            + self
+                .internal_type
+                .as_ref()
+                .map_or(0, InternalType::heap_size)
+            + crate::utils::params_heap_size(&self.components, self.components.capacity())
+    }
+
     #[inline]
     fn borrowed_internal_type(&self) -> Option<BorrowedInternalType<'_>> {
         self.internal_type().as_ref().map(|it| it.as_borrowed())
@@ -444,15 +818,117 @@ struct BorrowedParam<'a> {
         skip_serializing_if = "Option::is_none"
     )]
     internal_type: Option<BorrowedInternalType<'a>>,
-    #[serde(default, skip_serializing_if = "<[_]>::is_empty")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_components",
+        skip_serializing_if = "<[_]>::is_empty"
+    )]
     components: Cow<'a, [Param]>,
 }
 
+/// Some tools emit `null` instead of omitting the components.
+#[inline]
+fn deserialize_components<'de, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Cow<'a, [Param]>, D::Error> {
+    Option::<Vec<Param>>::deserialize(deserializer).map(|c| Cow::Owned(c.unwrap_or_default()))
+}
+
+/// The structure of a parameter's type, with tuples and arrays resolved.
+///
+/// Returned by [`AbiItem::input_trees`](crate::AbiItem::input_trees) and
+/// [`AbiItem::output_trees`](crate::AbiItem::output_trees).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TypeTree {
+    /// A basic or user-defined type, e.g. `uint256`.
+    Leaf(String),
+    /// An array of `inner` elements, of fixed `size` or dynamic if `None`.
+    Array {
+        /// The type of the elements.
+        inner: Box<TypeTree>,
+        /// The size of the array, or `None` if it is dynamic.
+        size: Option<usize>,
+    },
+    /// A tuple, with the types of its components.
+    Tuple(Vec<TypeTree>),
+}
+
+impl TypeTree {
+    /// Builds the type tree of a parameter.
+    ///
+    /// Errors if the type of the parameter, or of one of its components, is
+    /// not a valid type specifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_json_abi::{Function, TypeTree};
+    /// let f = Function::parse("foo((uint256,bool)[2] a)")?;
+    /// assert_eq!(
+    ///     TypeTree::from_param(&f.inputs[0])?,
+    ///     TypeTree::Array {
+    ///         inner: Box::new(TypeTree::Tuple(vec![
+    ///             TypeTree::Leaf("uint256".into()),
+    ///             TypeTree::Leaf("bool".into()),
+    ///         ])),
+    ///         size: Some(2),
+    ///     }
+    /// );
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn from_param(param: &Param) -> parser::Result<Self> {
+        Self::new(&param.ty, &param.components)
+    }
+
+    pub(crate) fn new(ty: &str, components: &[Param]) -> parser::Result<Self> {
+        let spec = TypeSpecifier::parse(ty)?;
+        let mut tree = match &spec.stem {
+            TypeStem::Root(root) if root.span() == "tuple" => Self::Tuple(
+                components
+                    .iter()
+                    .map(Self::from_param)
+                    .collect::<parser::Result<_>>()?,
+            ),
+            TypeStem::Root(root) => Self::Leaf(canonical_root_type(root.span())?.into()),
+            TypeStem::Tuple(tuple) => Self::Tuple(
+                tuple
+                    .types
+                    .iter()
+                    .map(|ty| Self::from_param(&Param::from_type_specifier("", ty)?))
+                    .collect::<parser::Result<_>>()?,
+            ),
+        };
+        for size in &spec.sizes {
+            tree = Self::Array {
+                inner: Box::new(tree),
+                size: size.map(|size| size.get()),
+            };
+        }
+        Ok(tree)
+    }
+}
+
+fn nesting_depth(params: &[Param]) -> usize {
+    params
+        .iter()
+        .map(|param| 1 + nesting_depth(&param.components))
+        .max()
+        .unwrap_or(0)
+}
+
 impl BorrowedParam<'_> {
     #[inline(always)]
     fn validate_fields<E: serde::de::Error>(&self) -> Result<(), E> {
         validate_identifier!(self.name);
 
+        // the components have been validated already, so this is bounded
+        if nesting_depth(&self.components) > Param::MAX_NESTING_DEPTH {
+            return Err(E::custom(format_args!(
+                "parameter components are nested deeper than {} levels",
+                Param::MAX_NESTING_DEPTH
+            )))
+        }
+
         // any components means type is "tuple" + maybe brackets, so we can skip
         // parsing with TypeSpecifier
         if self.components.is_empty() {
@@ -464,11 +940,15 @@ impl BorrowedParam<'_> {
             }
         } else {
             // https://docs.soliditylang.org/en/latest/abi-spec.html#handling-tuple-types
-            // checking for "tuple" prefix should be enough
-            if !self.ty.starts_with("tuple") {
+            // components are only allowed on `tuple`, optionally followed by
+            // array sizes
+            let is_tuple = alloy_sol_type_parser::TypeSpecifier::parse(self.ty)
+                .map(|spec| spec.stem.span() == "tuple")
+                .unwrap_or(false);
+            if !is_tuple {
                 return Err(E::invalid_value(
                     Unexpected::Str(self.ty),
-                    &"a string prefixed with `tuple`, optionally followed by a sequence of `[]` or `[k]` with integers `k`",
+                    &"`tuple`, optionally followed by a sequence of `[]` or `[k]` with integers `k`, since the parameter has components",
                 ))
             }
         }
@@ -1,6 +1,10 @@
-use crate::{EventParam, Param};
-use alloc::string::String;
+use crate::{EventParam, InternalType, Param, SignatureOptions, SpecViolation, StateMutability};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use alloy_primitives::Selector;
+use alloy_sol_type_parser::{
+    is_valid_identifier, Error as ParserError, Result, RootType, TypeSpecifier,
+};
+use core::fmt::Write;
 
 /// Capacity to allocate per [Param].
 const PARAM: usize = 32;
@@ -47,7 +51,51 @@ pub(crate) fn signature(name: &str, inputs: &[Param], outputs: Option<&[Param]>)
 
 /// `($($params),*)`
 pub(crate) fn signature_raw(params: &[Param], preimage: &mut String) {
-    signature!(params, preimage);
+    signature_with_options_raw(params, &SignatureOptions::default(), preimage);
+}
+
+/// `($($params),*)`, with the separator and parameter names given by `opts`.
+pub(crate) fn signature_with_options_raw(
+    params: &[Param],
+    opts: &SignatureOptions<'_>,
+    out: &mut String,
+) {
+    out.push('(');
+    for (i, param) in params.iter().enumerate() {
+        if i > 0 {
+            out.push_str(opts.separator);
+        }
+        if param.components.is_empty() {
+            out.push_str(&param.ty);
+        } else {
+            signature_with_options_raw(&param.components, opts, out);
+            if let Some(suffix) = param.ty.strip_prefix("tuple") {
+                out.push_str(suffix);
+            }
+        }
+        if opts.include_names && !param.name.is_empty() {
+            out.push(' ');
+            out.push_str(&param.name);
+        }
+    }
+    out.push(')');
+}
+
+/// Formats the canonical type of a parameter: `$ty`, or
+/// `($($components),*)$($suffix)*` for tuples.
+///
+/// Shared by function and event parameters, so that both flatten tuples
+/// identically.
+pub(crate) fn selector_type_raw(ty: &str, components: &[Param], preimage: &mut String) {
+    if components.is_empty() {
+        preimage.push_str(ty);
+    } else {
+        signature_raw(components, preimage);
+        // checked during deserialization, but might be invalid from a user
+        if let Some(suffix) = ty.strip_prefix("tuple") {
+            preimage.push_str(suffix);
+        }
+    }
 }
 
 /// `$name($($inputs),*)`
@@ -58,6 +106,157 @@ pub(crate) fn event_signature(name: &str, inputs: &[EventParam]) -> String {
     preimage
 }
 
+/// `($($ty $name),*)`, as used in human-readable signatures.
+///
+/// Errors if a name is not a valid identifier or a type is not a valid type
+/// string, since the output would not be parseable.
+pub(crate) fn human_readable_params(params: &[Param], out: &mut String) -> Result<()> {
+    out.push('(');
+    for (i, param) in params.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        human_readable_param(&param.ty, &param.components, false, &param.name, out)?;
+    }
+    out.push(')');
+    Ok(())
+}
+
+/// `($($ty $(indexed)? $name),*)`, as used in human-readable signatures.
+///
+/// See [`human_readable_params`].
+pub(crate) fn human_readable_event_params(params: &[EventParam], out: &mut String) -> Result<()> {
+    out.push('(');
+    for (i, param) in params.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        human_readable_param(
+            &param.ty,
+            &param.components,
+            param.indexed,
+            &param.name,
+            out,
+        )?;
+    }
+    out.push(')');
+    Ok(())
+}
+
+fn human_readable_param(
+    ty: &str,
+    components: &[Param],
+    indexed: bool,
+    name: &str,
+    out: &mut String,
+) -> Result<()> {
+    match ty.strip_prefix("tuple") {
+        Some(suffix) if suffix.is_empty() || suffix.starts_with('[') => {
+            human_readable_params(components, out)?;
+            out.push_str(suffix);
+        }
+        _ => {
+            TypeSpecifier::parse(ty)?;
+            out.push_str(ty);
+        }
+    }
+    if indexed {
+        out.push_str(" indexed");
+    }
+    if !name.is_empty() {
+        validate_name(name)?;
+        out.push(' ');
+        out.push_str(name);
+    }
+    Ok(())
+}
+
+/// Checks that `ty` is a tuple type if and only if there are `components`,
+/// recursively. `path` is the path to the parameter, used in the error.
+pub(crate) fn validate_tuple_consistency(
+    path: &mut String,
+    ty: &str,
+    components: &[Param],
+) -> Result<()> {
+    let is_tuple = TypeSpecifier::parse(ty).map_or(false, |spec| spec.stem.span() == "tuple");
+    if is_tuple == components.is_empty() {
+        let problem = if is_tuple {
+            "tuple type without components"
+        } else {
+            "non-tuple type with components"
+        };
+        return Err(ParserError::new(format_args!(
+            "{problem} at {path}: {ty:?}"
+        )))
+    }
+    let len = path.len();
+    for (i, component) in components.iter().enumerate() {
+        write!(path, ".components[{i}]").unwrap();
+        validate_tuple_consistency(path, &component.ty, &component.components)?;
+        path.truncate(len);
+    }
+    Ok(())
+}
+
+/// Collects the specification violations of a parameter into `out`,
+/// recursively. `path` is the path to the parameter.
+pub(crate) fn spec_violations(
+    path: &mut String,
+    name: &str,
+    ty: &str,
+    components: &[Param],
+    out: &mut Vec<SpecViolation>,
+) {
+    if !name.is_empty() && !is_valid_identifier(name) {
+        out.push(SpecViolation::InvalidName {
+            path: path.clone(),
+            name: name.into(),
+        });
+    }
+    match TypeSpecifier::parse(ty) {
+        Ok(spec) if validate_width(spec.stem.span()).is_ok() => {
+            let is_tuple = spec.stem.span() == "tuple";
+            if is_tuple && components.is_empty() {
+                out.push(SpecViolation::TupleWithoutComponents { path: path.clone() });
+            } else if !is_tuple && !components.is_empty() {
+                out.push(SpecViolation::ComponentsOnNonTuple { path: path.clone() });
+            }
+        }
+        _ => out.push(SpecViolation::InvalidType {
+            path: path.clone(),
+            ty: ty.into(),
+        }),
+    }
+    let len = path.len();
+    for (i, component) in components.iter().enumerate() {
+        write!(path, ".components[{i}]").unwrap();
+        spec_violations(
+            path,
+            &component.name,
+            &component.ty,
+            &component.components,
+            out,
+        );
+        path.truncate(len);
+    }
+}
+
+/// Errors if `name` is not a valid Solidity identifier.
+pub(crate) fn validate_name(name: &str) -> Result<()> {
+    if is_valid_identifier(name) {
+        Ok(())
+    } else {
+        Err(ParserError::new(format_args!(
+            "invalid identifier: {name:?}"
+        )))
+    }
+}
+
+/// Returns an estimate of the heap memory used by `params` and their data.
+pub(crate) fn params_heap_size(params: &[Param], capacity: usize) -> usize {
+    capacity * core::mem::size_of::<Param>() + params.iter().map(Param::heap_size).sum::<usize>()
+}
+
 /// `keccak256(preimage)[..4]`
 pub(crate) fn selector(preimage: &str) -> Selector {
     // SAFETY: splitting an array
@@ -70,9 +269,335 @@ pub(crate) fn selector(preimage: &str) -> Selector {
     }
 }
 
+/// Parses a human-readable signature: `$name($($inputs),*)$($rest)*`.
+///
+/// Returns the name, the parsed inputs and the unparsed rest of the string.
+pub(crate) fn parse_signature<P>(
+    s: &str,
+    parse_param: impl Fn(&str) -> Result<P>,
+) -> Result<(&str, Vec<P>, &str)> {
+    // declarations copied from Solidity sources end with a semicolon
+    let s = s.trim();
+    let s = s.strip_suffix(';').unwrap_or(s).trim_end();
+    let invalid = || ParserError::new(format_args!("invalid signature: {s:?}"));
+    let start = s.find('(').ok_or_else(invalid)?;
+    let end = start + matching_paren(&s[start..]).ok_or_else(invalid)?;
+    let name = s[..start].trim();
+    if !is_valid_identifier(name) {
+        return Err(ParserError::new(format_args!(
+            "invalid signature name: {name:?}"
+        )))
+    }
+    let inputs = split_params(&s[start + 1..end])?
+        .into_iter()
+        .map(parse_param)
+        .collect::<Result<_>>()?;
+    Ok((name, inputs, &s[end + 1..]))
+}
+
+/// Parses the modifiers that follow a function's inputs:
+/// `$($visibility)? $($state_mutability)? $(returns ($($outputs),*))?`.
+pub(crate) fn parse_function_modifiers(
+    mut s: &str,
+    allow_outputs: bool,
+) -> Result<(StateMutability, Vec<Param>)> {
+    let mut state_mutability = StateMutability::NonPayable;
+    let mut outputs = None;
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            break
+        }
+
+        if let Some(returns) = s.strip_prefix("returns") {
+            let returns = returns.trim_start();
+            if allow_outputs && outputs.is_none() && returns.starts_with('(') {
+                if let Some(end) = matching_paren(returns) {
+                    outputs = Some(parse_params(&returns[1..end])?);
+                    s = &returns[end + 1..];
+                    continue
+                }
+            }
+            return Err(ParserError::new(format_args!(
+                "invalid returns clause: {s:?}"
+            )))
+        }
+
+        let (word, rest) = split_word(s);
+        match word {
+            "external" | "public" => {}
+            "pure" => state_mutability = StateMutability::Pure,
+            "view" => state_mutability = StateMutability::View,
+            "payable" => state_mutability = StateMutability::Payable,
+            _ => {
+                return Err(ParserError::new(format_args!(
+                    "unexpected modifier: {word:?}"
+                )))
+            }
+        }
+        s = rest;
+    }
+    Ok((state_mutability, outputs.unwrap_or_default()))
+}
+
+/// Parses a comma-separated list of human-readable parameters.
+pub(crate) fn parse_params(s: &str) -> Result<Vec<Param>> {
+    split_params(s)?.into_iter().map(parse_param).collect()
+}
+
+/// Parses a human-readable parameter: `$ty $($location)? $($name)?`.
+pub(crate) fn parse_param(s: &str) -> Result<Param> {
+    parse_param_raw(s, false).map(|(param, _)| param)
+}
+
+/// Parses a human-readable event parameter:
+/// `$ty $(indexed)? $($name)?`.
+pub(crate) fn parse_event_param(s: &str) -> Result<EventParam> {
+    parse_param_raw(s, true).map(|(param, indexed)| EventParam {
+        name: param.name,
+        ty: param.ty,
+        indexed,
+        components: param.components,
+        internal_type: param.internal_type,
+    })
+}
+
+/// Expands parameters whose type names a struct in `registry` into tuples with
+/// the registered components, recursively. Array suffixes are preserved.
+///
+/// Library-qualified structs, e.g. `IERC20.Foo`, which are parsed as tuples
+/// without components, are looked up by their qualified name first, and then
+/// by their unqualified name.
+pub(crate) fn resolve_structs(
+    params: &mut [Param],
+    registry: &BTreeMap<String, Vec<Param>>,
+    depth: usize,
+) -> Result<()> {
+    for param in params {
+        let (name, suffix) = param
+            .ty
+            .split_at(param.ty.find('[').unwrap_or(param.ty.len()));
+        let resolved = match &param.internal_type {
+            Some(InternalType::Struct {
+                contract: Some(contract),
+                ty,
+            }) if name == "tuple" && param.components.is_empty() => {
+                let ty = ty.split_once('[').map_or(ty.as_str(), |(ty, _)| ty);
+                let qualified = format!("{contract}.{ty}");
+                registry
+                    .get(&qualified)
+                    .or_else(|| registry.get(ty))
+                    .map(|components| (qualified, components, None))
+            }
+            _ => registry.get(name).map(|components| {
+                let internal_type = InternalType::Struct {
+                    contract: None,
+                    ty: param.ty.clone(),
+                };
+                (name.into(), components, Some(internal_type))
+            }),
+        };
+        if let Some((name, components, internal_type)) = resolved {
+            if depth >= Param::MAX_NESTING_DEPTH {
+                return Err(ParserError::new(format_args!(
+                    "struct `{name}` is nested deeper than {} levels",
+                    Param::MAX_NESTING_DEPTH
+                )))
+            }
+            if internal_type.is_some() {
+                param.internal_type = internal_type;
+            }
+            param.ty = format!("tuple{suffix}");
+            param.components = components.clone();
+        }
+        resolve_structs(&mut param.components, registry, depth + 1)?;
+    }
+    Ok(())
+}
+
+fn parse_param_raw(s: &str, event: bool) -> Result<(Param, bool)> {
+    let s = s.trim();
+    let tuple = if s.starts_with('(') {
+        Some(s)
+    } else {
+        s.strip_prefix("tuple").filter(|t| t.starts_with('('))
+    };
+    let mut internal_type = None;
+    let (ty, components, rest) = match tuple {
+        Some(tuple) => {
+            let end = matching_paren(tuple).ok_or_else(|| ParserError::invalid_type_string(s))?;
+            let components = parse_params(&tuple[1..end])?;
+            let (suffix, rest) = split_word(&tuple[end + 1..]);
+            let ty = format!("tuple{suffix}");
+            // only array sizes may follow the tuple's components
+            match TypeSpecifier::parse(&ty) {
+                Ok(spec) if spec.stem.span() == "tuple" => {}
+                _ => {
+                    return Err(ParserError::invalid_type_string(
+                        &tuple[..end + 1 + suffix.len()],
+                    ))
+                }
+            }
+            (ty, components, rest)
+        }
+        None => {
+            let (ty, rest) = split_word(s);
+            if let Some((contract, name)) = ty.split_once('.') {
+                // library-qualified struct, e.g. `IERC20.Foo[]`, whose
+                // components are not known
+                let end = name.find('[').unwrap_or(name.len());
+                let ty = format!("tuple{}", &name[end..]);
+                if !is_valid_identifier(contract)
+                    || !is_valid_identifier(&name[..end])
+                    || TypeSpecifier::parse(&ty).is_err()
+                {
+                    return Err(ParserError::invalid_type_string(s))
+                }
+                internal_type = Some(InternalType::Struct {
+                    contract: Some(contract.into()),
+                    ty: name.into(),
+                });
+                (ty, Vec::new(), rest)
+            } else {
+                let spec = TypeSpecifier::parse(ty)?;
+                let stem = spec.stem.span();
+                let ty = format!("{}{}", canonical_root_type(stem)?, &ty[stem.len()..]);
+                (ty, Vec::new(), rest)
+            }
+        }
+    };
+
+    let mut words = rest.split_whitespace().peekable();
+    let mut indexed = event && words.next_if_eq(&"indexed").is_some();
+    words.next_if(|word| matches!(*word, "memory" | "calldata" | "storage"));
+    let name = words.next().unwrap_or_default();
+    // nonstandard, but `indexed` is also accepted after the name, only once
+    let duplicate_indexed = indexed && name == "indexed";
+    if event && !indexed && !name.is_empty() {
+        indexed = words.next_if_eq(&"indexed").is_some();
+    }
+    if duplicate_indexed
+        || words.next().is_some()
+        || !(name.is_empty() || is_valid_identifier(name))
+    {
+        return Err(ParserError::new(format_args!("invalid parameter: {s:?}")))
+    }
+
+    let param = Param {
+        name: name.into(),
+        ty,
+        components,
+        internal_type,
+    };
+    Ok((param, indexed))
+}
+
+/// Validates the width of the root type `ty`, see [`validate_width`], and
+/// normalizes its aliases to their canonical form: `uint` and `int` to
+/// `uint256` and `int256`, `fixed` and `ufixed` to `fixed128x18` and
+/// `ufixed128x18`, and the legacy `byte` to `bytes1`.
+pub(crate) fn canonical_root_type(ty: &str) -> Result<&str> {
+    validate_width(ty)?;
+    Ok(match ty {
+        "uint" => "uint256",
+        "int" => "int256",
+        "ufixed" => "ufixed128x18",
+        "fixed" => "fixed128x18",
+        // legacy alias, removed in Solidity 0.8
+        "byte" => "bytes1",
+        ty => ty,
+    })
+}
+
+/// Errors if `ty` is a sized `bytesN`, `uintN`, `intN`, `fixedMxN` or
+/// `ufixedMxN` type with an invalid size: `N` must be between 1 and 32 for
+/// `bytesN`, and `M` and `N` must be multiples of 8 between 8 and 256 for
+/// integers, and `N` at most 80 for fixed-point types.
+fn validate_width(ty: &str) -> Result<()> {
+    let is_size = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let fixed = ty
+        .strip_prefix("ufixed")
+        .or_else(|| ty.strip_prefix("fixed"))
+        .and_then(|mn| mn.split_once('x'))
+        .filter(|(m, n)| is_size(m) && is_size(n));
+    if let Some((m, n)) = fixed {
+        return match (m.parse::<usize>(), n.parse::<usize>()) {
+            (Ok(m), Ok(n)) if m % 8 == 0 && (8..=256).contains(&m) && n <= 80 => Ok(()),
+            _ => Err(ParserError::invalid_size(ty)),
+        }
+    }
+
+    let size = ty
+        .strip_prefix("bytes")
+        .or_else(|| ty.strip_prefix("uint"))
+        .or_else(|| ty.strip_prefix("int"));
+    match size {
+        Some(size) if is_size(size) => RootType::parse(ty)?.try_basic_solidity(),
+        _ => Ok(()),
+    }
+}
+
+/// Splits the body of a parameter list on its top-level commas.
+fn split_params(s: &str) -> Result<Vec<&str>> {
+    let mut params = Vec::new();
+    if s.trim().is_empty() {
+        return Ok(params)
+    }
+
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| ParserError::invalid_type_string(s))?
+            }
+            ',' if depth == 0 => {
+                params.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(ParserError::invalid_type_string(s))
+    }
+    params.push(&s[start..]);
+    Ok(params)
+}
+
+/// Returns the byte index of the parenthesis that closes the one at the
+/// start of `s`.
+fn matching_paren(s: &str) -> Option<usize> {
+    debug_assert!(s.starts_with('('));
+    let mut depth = 0usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i)
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits `s` at the first whitespace character.
+#[inline]
+fn split_word(s: &str) -> (&str, &str) {
+    s.split_once(char::is_whitespace).unwrap_or((s, ""))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
 
     fn param(kind: &str) -> Param {
         crate::Param {
@@ -135,6 +660,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_param_widths() {
+        for ty in [
+            "uint8",
+            "uint256",
+            "int8",
+            "int256",
+            "uint",
+            "int",
+            "bytes1",
+            "bytes32",
+            "bytes",
+            "uint64[2]",
+            "integer",
+            "bytesFoo",
+            "fixed128x18",
+            "ufixed128x18",
+            "fixed8x0",
+            "ufixed256x80[2][]",
+            "fixedFoo",
+            "fixed8y8",
+        ] {
+            parse_param(ty).unwrap_or_else(|e| panic!("{ty}: {e}"));
+        }
+        for ty in [
+            "uint0",
+            "uint7",
+            "uint264",
+            "uint300",
+            "int12",
+            "int512",
+            "bytes0",
+            "bytes33",
+            "uint7[]",
+            "bytes33[2]",
+            "fixed0x18",
+            "fixed7x18",
+            "ufixed264x18",
+            "fixed128x81",
+        ] {
+            let e = parse_param(ty).unwrap_err();
+            assert!(e.to_string().contains("invalid size"), "{ty}: {e}");
+        }
+        parse_param("(uint7 a)").unwrap_err();
+        parse_event_param("bytes40 indexed a").unwrap_err();
+    }
+
     #[test]
     fn test_event_signature() {
         assert_eq!(event_signature("foo", &[]), "foo()");
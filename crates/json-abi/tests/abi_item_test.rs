@@ -1,9 +1,9 @@
 mod test_helpers;
 
 use alloy_json_abi::{
-    AbiItem, Event, EventParam, Function,
+    AbiItem, AbiItemKind, Event, EventParam, Function,
     InternalType::{Other, Struct},
-    Param, StateMutability,
+    LintWarning, Param, SpecViolation, StateMutability,
 };
 use std::borrow::Cow;
 
@@ -138,3 +138,757 @@ fn event_operation_with_tuple_array_input() {
     assert_eq!(deserialized, AbiItem::Event(Cow::Owned(event)));
     assert_ser_de!(AbiItem<'_>, deserialized);
 }
+
+#[test]
+fn state_mutability_or_default() {
+    let function = AbiItem::parse("function foo() view").unwrap();
+    assert_eq!(function.state_mutability(), Some(StateMutability::View));
+    assert_eq!(
+        function.state_mutability_or_default(),
+        StateMutability::View
+    );
+
+    let event = AbiItem::parse("event Foo()").unwrap();
+    assert_eq!(event.state_mutability(), None);
+    assert_eq!(
+        event.state_mutability_or_default(),
+        StateMutability::NonPayable
+    );
+}
+
+#[test]
+fn kind() {
+    let items = [
+        ("constructor()", AbiItemKind::Constructor),
+        ("fallback()", AbiItemKind::Fallback),
+        ("receive() external payable", AbiItemKind::Receive),
+        ("function foo()", AbiItemKind::Function),
+        ("event Foo()", AbiItemKind::Event),
+        ("error Foo()", AbiItemKind::Error),
+    ];
+    for (s, kind) in items {
+        let item = AbiItem::parse(s).unwrap();
+        assert_eq!(item.kind(), kind);
+        assert_eq!(format!("{kind:?}"), item.debug_name());
+    }
+}
+
+#[test]
+fn map_name() {
+    let function = Function::parse("foo(uint256)").unwrap();
+    let mut item = AbiItem::from(&function);
+
+    item.map_name(str::to_string);
+    assert!(matches!(item, AbiItem::Function(Cow::Borrowed(_))));
+
+    item.map_name(|name| format!("{name}_1"));
+    assert!(matches!(item, AbiItem::Function(Cow::Owned(_))));
+    assert_eq!(item.name().unwrap(), "foo_1");
+    assert_eq!(function.name, "foo");
+
+    let mut item = AbiItem::parse("fallback()").unwrap();
+    item.map_name(|_| unreachable!());
+}
+
+#[test]
+fn event_outputs() {
+    let event = |outputs: &str| {
+        format!(r#"{{ "type": "event", "name": "E", "inputs": [], "anonymous": false{outputs} }}"#)
+    };
+    serde_json::from_str::<Event>(&event("")).unwrap();
+    serde_json::from_str::<Event>(&event(r#", "outputs": []"#)).unwrap();
+
+    let s = event(r#", "outputs": [{ "name": "a", "type": "uint256" }]"#);
+    let err = serde_json::from_str::<Event>(&s).unwrap_err();
+    assert!(
+        err.to_string().contains("events cannot have outputs"),
+        "{err}"
+    );
+    serde_json::from_str::<AbiItem<'_>>(&s).unwrap_err();
+    serde_json::from_str::<alloy_json_abi::JsonAbi>(&format!("[{s}]")).unwrap_err();
+}
+
+#[test]
+fn validate_tuple_consistency() {
+    let mut function =
+        Function::parse("foo((uint256 a, (bool b)[] c) x) returns (uint256)").unwrap();
+    AbiItem::from(&function)
+        .validate_tuple_consistency()
+        .unwrap();
+
+    function.inputs[0].components[1].components.clear();
+    let err = AbiItem::from(&function)
+        .validate_tuple_consistency()
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("tuple type without components at inputs[0].components[1]"),
+        "{err}"
+    );
+
+    let mut function = Function::parse("foo() returns (uint256, bool)").unwrap();
+    let component = function.outputs[0].clone();
+    function.outputs[1].components.push(component);
+    let err = AbiItem::from(&function)
+        .validate_tuple_consistency()
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("non-tuple type with components at outputs[1]"),
+        "{err}"
+    );
+
+    let mut event = Event::parse("Foo(uint256 indexed a, (bool) b)").unwrap();
+    AbiItem::from(&event).validate_tuple_consistency().unwrap();
+    event.inputs[1].ty = "bool".into();
+    let err = AbiItem::from(&event)
+        .validate_tuple_consistency()
+        .unwrap_err();
+    assert!(err.to_string().contains("at inputs[1]"), "{err}");
+}
+
+#[test]
+fn with_name() {
+    let function = Function::parse("foo(uint256 a) returns (bool)").unwrap();
+    let renamed = AbiItem::from(&function).with_name("bar").unwrap();
+    let AbiItem::Function(renamed) = renamed else {
+        panic!("{renamed:?}")
+    };
+    assert_eq!(renamed.name, "bar");
+    assert_eq!(renamed.inputs, function.inputs);
+    assert_eq!(renamed.outputs, function.outputs);
+    assert_eq!(function.name, "foo");
+
+    let event = AbiItem::parse("event Foo(uint256 indexed a)").unwrap();
+    assert_eq!(event.with_name("Bar").unwrap().name().unwrap(), "Bar");
+
+    let fallback = AbiItem::parse("fallback()").unwrap();
+    assert_eq!(fallback.clone().with_name("foo").unwrap(), fallback);
+
+    AbiItem::from(&function).with_name("").unwrap_err();
+    AbiItem::from(&function).with_name("foo bar").unwrap_err();
+}
+
+#[test]
+fn input_by_name() {
+    let item = AbiItem::parse("function transfer(address to, uint256 amount, bool)").unwrap();
+    let (index, param) = item.input_by_name("amount").unwrap();
+    assert_eq!(index, 1);
+    assert_eq!(param.ty, "uint256");
+    assert_eq!(item.input_by_name("to").unwrap().0, 0);
+    assert!(item.input_by_name("from").is_none());
+    assert!(item.input_by_name("").is_none());
+
+    let item = AbiItem::parse("constructor((uint256 a) config)").unwrap();
+    assert_eq!(item.input_by_name("config").unwrap().0, 0);
+    assert!(item.input_by_name("a").is_none());
+
+    let item = AbiItem::parse("event Transfer(address indexed to)").unwrap();
+    assert!(item.input_by_name("to").is_none());
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn abi_hash() {
+    let items = [
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+        "constructor()",
+        "error Unauthorized()",
+    ]
+    .map(|s| AbiItem::parse(s).unwrap());
+    let hash = AbiItem::abi_hash(&items);
+
+    let preimage = [2, 0, 1, 3]
+        .map(|i| serde_json::to_string(&items[i]).unwrap())
+        .concat();
+    assert_eq!(hash, alloy_primitives::keccak256(preimage));
+
+    let mut reversed = items.clone();
+    reversed.reverse();
+    assert_eq!(AbiItem::abi_hash(&reversed), hash);
+
+    let mut renamed = items.clone();
+    renamed[0].map_name(|_| "transferFrom".into());
+    assert_ne!(AbiItem::abi_hash(&renamed), hash);
+    assert_ne!(AbiItem::abi_hash(&items[..3]), hash);
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn deserialize_lenient() {
+    let json = r#"[
+        { "type": "function", "name": "foo", "inputs": [], "outputs": [], "stateMutability": "view" },
+        { "type": "function", "name": "bar", "inputs": [{ "name": "a", "type": "uint256[" }], "outputs": [], "stateMutability": "view" },
+        { "type": "unknown" },
+        42,
+        { "type": "event", "name": "Foo", "inputs": [], "anonymous": false }
+    ]"#;
+    let (items, errors) = AbiItem::deserialize_lenient(json).unwrap();
+    let names: Vec<_> = items
+        .iter()
+        .map(|item| item.name().unwrap().as_str())
+        .collect();
+    assert_eq!(names, ["foo", "Foo"]);
+    let indices: Vec<_> = errors.iter().map(|(i, _)| *i).collect();
+    assert_eq!(indices, [1, 2, 3]);
+
+    AbiItem::deserialize_lenient("{}").unwrap_err();
+    assert_eq!(AbiItem::deserialize_lenient("[]").unwrap().0, []);
+}
+
+#[test]
+fn lint() {
+    let lint = |s: &str| AbiItem::parse(s).unwrap().lint();
+    assert_eq!(
+        lint("function getOwner() returns (address)"),
+        [LintWarning::SuspiciousMutability]
+    );
+    assert_eq!(
+        lint("function get_owner() returns (address)"),
+        [LintWarning::SuspiciousMutability]
+    );
+    assert!(lint("function getOwner() view returns (address)").is_empty());
+    assert!(lint("function getOwner(uint256) returns (address)").is_empty());
+    assert!(lint("function getOwner()").is_empty());
+    assert!(lint("function getaway() returns (bool)").is_empty());
+    assert!(lint("function getOwner() payable returns (address)").is_empty());
+    assert!(lint("event getOwner()").is_empty());
+}
+
+#[test]
+fn heap_size() {
+    assert_eq!(AbiItem::parse("fallback()").unwrap().heap_size(), 0);
+
+    let small = AbiItem::parse("function f(uint256 a)").unwrap();
+    let large = AbiItem::parse(
+        "function transferWithData((address to, uint256 value, bytes data)[] actions, string memo)",
+    )
+    .unwrap();
+    let param_size = std::mem::size_of::<Param>();
+    assert!(small.heap_size() >= "f".len() + param_size + "uint256".len() + "a".len());
+    assert!(large.heap_size() > small.heap_size() + 4 * param_size);
+}
+
+#[test]
+fn payable_entry_points() {
+    let items = [
+        "constructor() payable",
+        "function deposit() payable",
+        "function withdraw(uint256)",
+        "function balance() view returns (uint256)",
+        "fallback() external",
+        "receive() external payable",
+        "event Deposit(uint256)",
+    ]
+    .map(|s| AbiItem::parse(s).unwrap());
+    let entry_points: Vec<_> = AbiItem::payable_entry_points(&items)
+        .into_iter()
+        .map(|item| item.kind())
+        .collect();
+    assert_eq!(entry_points, [AbiItemKind::Function, AbiItemKind::Receive]);
+
+    let items = [AbiItem::parse("fallback() external payable").unwrap()];
+    assert_eq!(AbiItem::payable_entry_points(&items).len(), 1);
+}
+
+#[test]
+fn is_anonymous() {
+    let event = AbiItem::parse("event Transfer(address indexed, uint256)").unwrap();
+    assert_eq!(event.is_anonymous(), Some(false));
+    let event = AbiItem::parse("event Transfer(address indexed, uint256) anonymous").unwrap();
+    assert_eq!(event.is_anonymous(), Some(true));
+    let function = AbiItem::parse("function transfer(address, uint256)").unwrap();
+    assert_eq!(function.is_anonymous(), None);
+}
+
+#[test]
+fn short_id() {
+    let ids: Vec<_> = [
+        "constructor(uint256)",
+        "fallback() external",
+        "receive() external payable",
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+        "error Unauthorized()",
+    ]
+    .iter()
+    .map(|s| AbiItem::parse(s).unwrap().short_id())
+    .collect();
+    assert_eq!(
+        ids,
+        [
+            "ctor",
+            "fallback",
+            "receive",
+            "fn:a9059cbb",
+            "ev:ddf252ad",
+            "err:82b42900"
+        ]
+    );
+}
+
+#[test]
+fn has_duplicate_param_names() {
+    let cases = [
+        (
+            "function f(uint256 a, uint256 b) returns (uint256 a)",
+            false,
+        ),
+        ("function f(uint256 a, address a)", true),
+        ("function f(uint256, address, bool)", false),
+        ("function f() returns (uint256 x, uint256 x)", true),
+        ("event E(uint256 indexed a, uint256 a)", true),
+        ("event E(uint256 indexed a, uint256 b)", false),
+        ("error E(bytes data, string data)", true),
+        ("constructor(uint256 a, uint256 a)", true),
+        ("receive() external payable", false),
+    ];
+    for (s, expected) in cases {
+        let item = AbiItem::parse(s).unwrap();
+        assert_eq!(item.has_duplicate_param_names(), expected, "{s}");
+    }
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn parse_artifact() {
+    let json = r#"{
+        "_format": "hh-sol-artifact-1",
+        "contractName": "Token",
+        "abi": [
+            {
+                "type": "function",
+                "name": "transfer",
+                "inputs": [
+                    { "name": "to", "type": "address" },
+                    { "name": "amount", "type": "uint256" }
+                ],
+                "outputs": [{ "name": "", "type": "bool" }],
+                "stateMutability": "nonpayable"
+            },
+            { "type": "receive", "stateMutability": "payable" }
+        ],
+        "bytecode": { "object": "0x6080" }
+    }"#;
+    let items = AbiItem::parse_artifact(json).unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].short_id(), "fn:a9059cbb");
+    assert_eq!(items[1].kind(), AbiItemKind::Receive);
+
+    assert!(AbiItem::parse_artifact(r#"{ "abi": [] }"#)
+        .unwrap()
+        .is_empty());
+    AbiItem::parse_artifact("[]").unwrap_err();
+    AbiItem::parse_artifact(r#"{ "abi": [{ "type": "nope" }] }"#).unwrap_err();
+    let err = AbiItem::parse_artifact(r#"{ "contractName": "Token" }"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `abi`"), "{err}");
+}
+
+#[test]
+fn try_into_function_and_event() {
+    let items = [
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+        "error Unauthorized()",
+    ]
+    .map(|s| AbiItem::parse(s).unwrap());
+
+    let functions: Vec<_> = items
+        .iter()
+        .cloned()
+        .filter_map(|item| item.try_into_function().ok())
+        .collect();
+    assert_eq!(functions.len(), 1);
+    assert_eq!(functions[0].signature(), "transfer(address,uint256)");
+
+    let events: Vec<_> = items
+        .iter()
+        .cloned()
+        .filter_map(|item| item.try_into_event().ok())
+        .collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].name, "Transfer");
+
+    let error = items[2].clone();
+    assert_eq!(error.clone().try_into_function(), Err(error.clone()));
+    assert_eq!(error.clone().try_into_event(), Err(error));
+
+    let function = Function::parse("foo()").unwrap();
+    assert_eq!(
+        AbiItem::from(&function).try_into_function(),
+        Ok(function.clone())
+    );
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn from_json_strict() {
+    let clean = r#"{
+        "type": "function",
+        "name": "fill",
+        "inputs": [
+            {
+                "name": "order",
+                "type": "tuple",
+                "internalType": "struct Order",
+                "components": [{ "name": "maker", "type": "address" }]
+            }
+        ],
+        "outputs": [],
+        "stateMutability": "nonpayable"
+    }"#;
+    assert_eq!(
+        AbiItem::from_json_strict(clean).unwrap(),
+        serde_json::from_str::<AbiItem<'_>>(clean).unwrap()
+    );
+    AbiItem::from_json_strict(
+        r#"{ "type": "event", "name": "E", "inputs": [{ "name": "a", "type": "uint256", "indexed": true }], "anonymous": false }"#,
+    )
+    .unwrap();
+    AbiItem::from_json_strict(r#"{ "type": "receive", "stateMutability": "payable" }"#).unwrap();
+
+    let cases = [
+        (
+            r#"{ "type": "function", "name": "f", "inputs": [], "outputs": [], "stateMutability": "view", "constant": true }"#,
+            "unknown field `constant` in item",
+        ),
+        (
+            r#"{ "type": "error", "name": "E", "inputs": [{ "name": "a", "type": "uint256", "unit": "wei" }] }"#,
+            "unknown field `unit` in inputs[0]",
+        ),
+        (
+            r#"{ "type": "function", "name": "f", "inputs": [], "outputs": [{ "name": "", "type": "tuple", "components": [{ "name": "x", "type": "bool", "foo": 1 }] }], "stateMutability": "view" }"#,
+            "unknown field `foo` in outputs[0].components[0]",
+        ),
+        (
+            r#"{ "type": "event", "name": "E", "inputs": [], "anonymous": false, "outputs": [] }"#,
+            "unknown field `outputs` in item",
+        ),
+    ];
+    for (json, expected) in cases {
+        serde_json::from_str::<AbiItem<'_>>(json).unwrap();
+        let err = AbiItem::from_json_strict(json).unwrap_err();
+        assert!(err.to_string().contains(expected), "{err}");
+    }
+
+    AbiItem::from_json_strict(r#"{ "type": "nope" }"#).unwrap_err();
+}
+
+#[test]
+fn validate_spec() {
+    for s in [
+        "constructor(uint256 a) payable",
+        "function f((uint256 a, bool[] b)[2] c) view returns (bytes)",
+        "event E(uint256 indexed a, uint256 indexed b, uint256 indexed c, uint256 d)",
+        "event E(uint256 indexed a, uint256 indexed b, uint256 indexed c, uint256 indexed d) anonymous",
+        "error E(string reason)",
+        "receive() external payable",
+    ] {
+        assert_eq!(AbiItem::parse(s).unwrap().validate_spec(), Ok(()), "{s}");
+    }
+
+    let event = AbiItem::parse(
+        "event E(uint256 indexed a, uint256 indexed b, uint256 indexed c, uint256 indexed d)",
+    )
+    .unwrap();
+    assert_eq!(
+        event.validate_spec(),
+        Err(vec![SpecViolation::TooManyIndexed(4)])
+    );
+
+    let mut function = Function::parse("f((uint256 a) b, address c)").unwrap();
+    function.name = "not valid".into();
+    function.inputs[0].components[0].ty = "uint7".into();
+    function.inputs[0].components.push(Param {
+        name: "1d".into(),
+        ty: "tuple".into(),
+        components: vec![],
+        internal_type: None,
+    });
+    function.inputs[1].components = function.inputs[0].components.clone();
+    let violations = AbiItem::from(&function).validate_spec().unwrap_err();
+    assert_eq!(
+        violations,
+        [
+            SpecViolation::InvalidName {
+                path: "name".into(),
+                name: "not valid".into()
+            },
+            SpecViolation::InvalidType {
+                path: "inputs[0].components[0]".into(),
+                ty: "uint7".into()
+            },
+            SpecViolation::InvalidName {
+                path: "inputs[0].components[1]".into(),
+                name: "1d".into()
+            },
+            SpecViolation::TupleWithoutComponents {
+                path: "inputs[0].components[1]".into()
+            },
+            SpecViolation::ComponentsOnNonTuple {
+                path: "inputs[1]".into()
+            },
+            SpecViolation::InvalidType {
+                path: "inputs[1].components[0]".into(),
+                ty: "uint7".into()
+            },
+            SpecViolation::InvalidName {
+                path: "inputs[1].components[1]".into(),
+                name: "1d".into()
+            },
+            SpecViolation::TupleWithoutComponents {
+                path: "inputs[1].components[1]".into()
+            },
+        ]
+    );
+    assert_eq!(
+        violations[1].to_string(),
+        "invalid type at inputs[0].components[0]: \"uint7\""
+    );
+
+    let receive = AbiItem::Receive(Cow::Owned(alloy_json_abi::Receive {
+        state_mutability: StateMutability::NonPayable,
+    }));
+    assert_eq!(
+        receive.validate_spec(),
+        Err(vec![SpecViolation::InvalidStateMutability(
+            StateMutability::NonPayable
+        )])
+    );
+    let constructor = AbiItem::parse("constructor()").unwrap();
+    assert_eq!(constructor.validate_spec(), Ok(()));
+}
+
+#[test]
+fn input_and_output_trees() {
+    use alloy_json_abi::TypeTree::{self, Array, Leaf, Tuple};
+
+    let leaf = |ty: &str| Leaf(ty.into());
+    let array = |inner: TypeTree, size: Option<usize>| Array {
+        inner: Box::new(inner),
+        size,
+    };
+
+    let item = AbiItem::parse(
+        "function f((address a, uint256[] b)[2][] c, bytes d) returns (uint8[3], (bool) e)",
+    )
+    .unwrap();
+    assert_eq!(
+        item.input_trees().unwrap(),
+        [
+            array(
+                array(
+                    Tuple(vec![leaf("address"), array(leaf("uint256"), None)]),
+                    Some(2)
+                ),
+                None
+            ),
+            leaf("bytes"),
+        ]
+    );
+    assert_eq!(
+        item.output_trees().unwrap(),
+        [array(leaf("uint8"), Some(3)), Tuple(vec![leaf("bool")])]
+    );
+
+    let event = AbiItem::parse("event E((string, bytes32) indexed a, uint256 b)").unwrap();
+    assert_eq!(
+        event.input_trees().unwrap(),
+        [
+            Tuple(vec![leaf("string"), leaf("bytes32")]),
+            leaf("uint256")
+        ]
+    );
+    assert!(event.output_trees().unwrap().is_empty());
+
+    let receive = AbiItem::parse("receive() external payable").unwrap();
+    assert!(receive.input_trees().unwrap().is_empty());
+
+    let inline = Param {
+        ty: "(uint256,(bool,address)[])".into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        TypeTree::from_param(&inline).unwrap(),
+        Tuple(vec![
+            leaf("uint256"),
+            array(Tuple(vec![leaf("bool"), leaf("address")]), None)
+        ])
+    );
+    let aliased = Param {
+        ty: "(uint,byte)[]".into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        TypeTree::from_param(&aliased).unwrap(),
+        array(Tuple(vec![leaf("uint256"), leaf("bytes1")]), None)
+    );
+    let invalid = Param {
+        ty: "uint256[".into(),
+        ..Default::default()
+    };
+    TypeTree::from_param(&invalid).unwrap_err();
+}
+
+#[test]
+fn to_solidity() {
+    let cases = [
+        (
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "function transfer(address to, uint256 amount) external returns (bool);",
+        ),
+        (
+            "function balanceOf(address owner) view returns (uint256)",
+            "function balanceOf(address owner) external view returns (uint256);",
+        ),
+        (
+            "function deposit() payable",
+            "function deposit() external payable;",
+        ),
+        (
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "event Transfer(address indexed from, address indexed to, uint256 value);",
+        ),
+        (
+            "error Unauthorized(address caller)",
+            "error Unauthorized(address caller);",
+        ),
+        (
+            "fallback() external payable",
+            "fallback() external payable;",
+        ),
+        ("receive() external payable", "receive() external payable;"),
+    ];
+    for (input, expected) in cases {
+        let item = AbiItem::parse(input).unwrap();
+        assert_eq!(item.to_solidity().as_deref(), Some(expected), "{input}");
+    }
+
+    let constructor = AbiItem::parse("constructor(address owner)").unwrap();
+    assert_eq!(constructor.to_solidity(), None);
+}
+
+#[test]
+fn to_record() {
+    let records: Vec<_> = [
+        "constructor(uint256)",
+        "fallback() external",
+        "receive() external payable",
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+        "error Unauthorized()",
+    ]
+    .iter()
+    .map(|s| AbiItem::parse(s).unwrap().to_record())
+    .collect();
+    let some = |s: &str| Some(s.to_string());
+    assert_eq!(
+        records,
+        [
+            (AbiItemKind::Constructor, None, some("constructor(uint256)")),
+            (AbiItemKind::Fallback, None, None),
+            (AbiItemKind::Receive, None, None),
+            (
+                AbiItemKind::Function,
+                some("transfer"),
+                some("transfer(address,uint256)")
+            ),
+            (
+                AbiItemKind::Event,
+                some("Transfer"),
+                some("Transfer(address,address,uint256)")
+            ),
+            (
+                AbiItemKind::Error,
+                some("Unauthorized"),
+                some("Unauthorized()")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn merge_abis_prefer() {
+    let parse = |items: &[&str]| -> Vec<AbiItem<'static>> {
+        items.iter().map(|s| AbiItem::parse(s).unwrap()).collect()
+    };
+    let base = parse(&[
+        "constructor(address owner)",
+        "function upgradeTo(address impl)",
+        "function balanceOf(address) view returns (uint256)",
+        "event Upgraded(address indexed impl)",
+        "error Unauthorized()",
+        "fallback() external",
+    ]);
+    let overlay = parse(&[
+        "function upgradeTo(address newImplementation) payable",
+        "event Upgraded(address indexed implementation)",
+        "error Unauthorized()",
+        "fallback() external payable",
+    ]);
+
+    let merged = AbiItem::merge_abis_prefer(base.clone(), overlay.clone());
+    let mut expected = vec![base[0].clone(), base[2].clone()];
+    expected.extend(overlay.iter().cloned());
+    assert_eq!(merged, expected);
+
+    assert_eq!(AbiItem::merge_abis_prefer(base.clone(), Vec::new()), base);
+    assert_eq!(
+        AbiItem::merge_abis_prefer(Vec::new(), overlay.clone()),
+        overlay
+    );
+}
+
+#[test]
+fn selector_collides_with() {
+    let parse = |s: &str| AbiItem::parse(s).unwrap();
+    let transfer = parse("function transfer(address to, uint256 amount) returns (bool)");
+    let transfer_error = parse("error many_msg_babbage(bytes1)");
+    let transfer_fn = parse("function func_2093253501(bytes)");
+    let event = parse("event Transfer(address indexed from, address indexed to, uint256 value)");
+    let event_unindexed = parse("event Transfer(address from, address to, uint256 value)");
+    let approval = parse("event Approval(address indexed owner, address indexed spender, uint256)");
+    let receive = parse("receive() external payable");
+
+    assert!(transfer.selector_collides_with(&transfer));
+    assert!(transfer.selector_collides_with(&transfer_error));
+    assert!(transfer_error.selector_collides_with(&transfer));
+    assert!(transfer.selector_collides_with(&transfer_fn));
+    assert!(event.selector_collides_with(&event_unindexed));
+    assert!(!event.selector_collides_with(&approval));
+    assert!(!transfer.selector_collides_with(&parse("error Unauthorized()")));
+
+    assert!(!transfer.selector_collides_with(&event));
+    assert!(!event.selector_collides_with(&transfer));
+    assert!(!receive.selector_collides_with(&receive));
+    assert!(!receive.selector_collides_with(&transfer));
+}
+
+#[test]
+fn clone_owned() {
+    fn owned(function: &Function) -> AbiItem<'static> {
+        let borrowed = AbiItem::from(function);
+        assert!(matches!(
+            borrowed.clone(),
+            AbiItem::Function(Cow::Borrowed(_))
+        ));
+        borrowed.clone_owned()
+    }
+
+    let function = Function::parse("transfer(address to, uint256 amount) returns (bool)").unwrap();
+    let item = owned(&function);
+    drop(function);
+    assert!(matches!(item, AbiItem::Function(Cow::Owned(_))));
+    assert_eq!(item.name().unwrap(), "transfer");
+    assert_eq!(item.clone_owned(), item);
+
+    let event =
+        Event::parse("Transfer(address indexed from, address indexed to, uint256)").unwrap();
+    let borrowed = AbiItem::from(&event);
+    assert_eq!(borrowed.clone_owned(), borrowed);
+    assert!(matches!(
+        borrowed.clone_owned(),
+        AbiItem::Event(Cow::Owned(_))
+    ));
+}
@@ -1,6 +1,6 @@
 mod test_helpers;
 
-use alloy_json_abi::{EventParam, Param};
+use alloy_json_abi::{Event, EventParam, Param};
 
 #[test]
 fn event_param_deserialization() {
@@ -272,3 +272,108 @@ fn event_param_tuple_array_deserialization() {
 
     assert_json_eq!(s, serde_json::to_string(&deserialized).unwrap().as_str());
 }
+
+#[test]
+fn event_param_indexed_round_trip() {
+    let s = r#"{
+            "name": "Foo",
+            "type": "event",
+            "inputs": [
+                { "name": "a", "type": "address", "indexed": true },
+                { "name": "b", "type": "uint256", "indexed": false },
+                { "name": "c", "type": "bytes32", "indexed": true },
+                { "name": "d", "type": "bool" }
+            ],
+            "anonymous": false
+        }"#;
+    let event: Event = serde_json::from_str(s).unwrap();
+    let indexed: Vec<_> = event.inputs.iter().map(|p| p.indexed).collect();
+    assert_eq!(indexed, [true, false, true, false]);
+
+    let json = serde_json::to_string(&event).unwrap();
+    assert_eq!(json.matches(r#""indexed":true"#).count(), 2);
+    assert_eq!(json.matches(r#""indexed":false"#).count(), 2);
+    assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+
+    let param: EventParam = serde_json::from_str(r#"{ "name": "d", "type": "bool" }"#).unwrap();
+    assert!(!param.indexed);
+}
+
+#[test]
+fn event_param_indexed_tuple_round_trip() {
+    let s = r#"{
+            "name": "OrderFilled",
+            "type": "event",
+            "inputs": [
+                {
+                    "name": "order",
+                    "type": "tuple",
+                    "indexed": true,
+                    "internalType": "struct Exchange.Order",
+                    "components": [
+                        { "name": "maker", "type": "address", "internalType": "address" },
+                        {
+                            "name": "fees",
+                            "type": "tuple[]",
+                            "internalType": "struct Exchange.Fee[]",
+                            "components": [
+                                { "name": "recipient", "type": "address", "internalType": "address" },
+                                { "name": "amount", "type": "uint256", "internalType": "uint256" }
+                            ]
+                        }
+                    ]
+                },
+                {
+                    "name": "fills",
+                    "type": "tuple[2]",
+                    "indexed": true,
+                    "components": [{ "name": "amount", "type": "uint256" }]
+                },
+                { "name": "taker", "type": "address", "indexed": false }
+            ],
+            "anonymous": false
+        }"#;
+    let event: Event = serde_json::from_str(s).unwrap();
+    assert!(event.inputs[0].indexed);
+    assert_eq!(event.inputs[0].components.len(), 2);
+    assert_eq!(event.inputs[0].components[1].components.len(), 2);
+    assert!(event.inputs[1].indexed);
+    assert_eq!(event.inputs[1].components.len(), 1);
+    assert_eq!(
+        event.signature(),
+        "OrderFilled((address,(address,uint256)[]),(uint256)[2],address)"
+    );
+
+    let json = serde_json::to_string(&event).unwrap();
+    assert_json_eq!(s, json.as_str());
+    assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+    assert_ser_de!(EventParam, event.inputs[0]);
+    assert_ser_de!(EventParam, event.inputs[1]);
+}
+
+#[test]
+fn event_param_is_hashed_when_indexed() {
+    let event = Event::parse(
+        "Log(string indexed a, bytes indexed b, uint256[2] indexed c, (address,bool) indexed d, \
+         address indexed e, bytes32 indexed f, uint8 g)",
+    )
+    .unwrap();
+    let hashed: Vec<_> = event
+        .inputs
+        .iter()
+        .map(EventParam::is_hashed_when_indexed)
+        .collect();
+    assert_eq!(hashed, [true, true, true, true, false, false, false]);
+}
+
+#[test]
+fn event_param_components_accessors() {
+    let event = Event::parse("E((uint256 a, bool b) indexed x, address y)").unwrap();
+    let mut x = event.inputs[0].clone();
+    assert_eq!(x.components().len(), 2);
+    assert!(event.inputs[1].components().is_empty());
+
+    x.components_mut()[1].ty = "bytes".into();
+    x.components_mut().swap(0, 1);
+    assert_eq!(x.selector_type(), "(bytes,uint256)");
+}
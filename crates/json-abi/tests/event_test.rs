@@ -0,0 +1,339 @@
+use alloy_json_abi::{Event, EventParam, Function, Param, TopicSlot};
+use alloy_primitives::B256;
+
+fn eparam(name: &str, ty: &str, indexed: bool) -> EventParam {
+    EventParam {
+        name: name.into(),
+        ty: ty.into(),
+        indexed,
+        components: vec![],
+        internal_type: None,
+    }
+}
+
+fn param(name: &str, ty: &str) -> Param {
+    Param {
+        name: name.into(),
+        ty: ty.into(),
+        components: vec![],
+        internal_type: None,
+    }
+}
+
+#[test]
+fn indexed_types() {
+    let mut tuple = eparam("c", "tuple[]", true);
+    tuple.components = vec![param("x", "uint256"), param("y", "bool")];
+    let event = Event {
+        name: "Foo".into(),
+        inputs: vec![
+            eparam("a", "address", true),
+            eparam("b", "uint256", false),
+            tuple,
+            eparam("d", "string", true),
+        ],
+        anonymous: false,
+    };
+    assert_eq!(
+        event.indexed_types(),
+        ["address", "(uint256,bool)[]", "string"]
+    );
+
+    let event = Event {
+        name: "Bar".into(),
+        inputs: vec![eparam("a", "address", false)],
+        anonymous: false,
+    };
+    assert!(event.indexed_types().is_empty());
+}
+
+#[test]
+fn topic_layout() {
+    let mut tuple = eparam("c", "tuple", true);
+    tuple.components = vec![param("x", "uint256")];
+    let mut event = Event {
+        name: "Foo".into(),
+        inputs: vec![
+            eparam("a", "address", true),
+            eparam("b", "uint256", false),
+            tuple,
+            eparam("d", "uint8[2]", true),
+        ],
+        anonymous: false,
+    };
+    assert_eq!(event.topic0_only(), event.selector());
+    assert_eq!(
+        event.topic_layout(),
+        [
+            TopicSlot {
+                topic: 1,
+                param: 0,
+                hashed: false
+            },
+            TopicSlot {
+                topic: 2,
+                param: 2,
+                hashed: true
+            },
+            TopicSlot {
+                topic: 3,
+                param: 3,
+                hashed: true
+            },
+        ]
+    );
+
+    event.anonymous = true;
+    let topics: Vec<_> = event.topic_layout().iter().map(|slot| slot.topic).collect();
+    assert_eq!(topics, [0, 1, 2]);
+}
+
+#[test]
+fn with_indexed_first() {
+    let event = Event {
+        name: "Foo".into(),
+        inputs: vec![
+            eparam("a", "uint256", false),
+            eparam("b", "address", true),
+            eparam("c", "bool", false),
+            eparam("d", "bytes32", true),
+        ],
+        anonymous: false,
+    };
+    let reordered = event.with_indexed_first();
+    let names: Vec<_> = reordered.inputs.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["b", "d", "a", "c"]);
+
+    // the selector depends on the original order
+    assert_eq!(event.signature(), "Foo(uint256,address,bool,bytes32)");
+    assert_eq!(reordered.signature(), "Foo(address,bytes32,uint256,bool)");
+    assert_ne!(event.selector(), reordered.selector());
+    assert_eq!(event.with_indexed_first().with_indexed_first(), reordered);
+}
+
+#[test]
+fn selector_type_matches_function() {
+    let event =
+        Event::parse("Foo((uint256 a, (bool b, bytes c)[] d)[2] indexed x, string y)").unwrap();
+    let function =
+        Function::parse("Foo((uint256 a, (bool b, bytes c)[] d)[2] x, string y)").unwrap();
+    for (event_param, param) in event.inputs.iter().zip(&function.inputs) {
+        assert_eq!(event_param.selector_type(), param.selector_type());
+    }
+    assert_eq!(
+        event.inputs[0].selector_type(),
+        "(uint256,(bool,bytes)[])[2]"
+    );
+    assert_eq!(event.signature(), function.signature());
+}
+
+#[test]
+fn parse_event_keyword() {
+    let bare =
+        Event::parse("Transfer(address indexed from, address indexed to, uint256 value)").unwrap();
+    let prefixed =
+        Event::parse("event Transfer(address indexed from, address indexed to, uint256 value)")
+            .unwrap();
+    assert_eq!(prefixed, bare);
+    assert_eq!(
+        Event::parse("  event\tTransfer(address indexed from, address indexed to, uint256 value);")
+            .unwrap(),
+        bare
+    );
+    assert_eq!(
+        Event::parse("event Log(uint256) anonymous").unwrap(),
+        Event {
+            name: "Log".into(),
+            inputs: vec![eparam("", "uint256", false)],
+            anonymous: true,
+        }
+    );
+
+    Event::parse("event").unwrap_err();
+    Event::parse("event ").unwrap_err();
+    Event::parse("event event Transfer(uint256)").unwrap_err();
+}
+
+#[test]
+fn matches_log() {
+    let event =
+        Event::parse("Transfer(address indexed from, address indexed to, uint256 value)").unwrap();
+    let topics = [event.selector(), B256::ZERO, B256::ZERO];
+    assert!(event.matches_log(&topics, 32));
+    assert!(event.matches_log(&topics, 0));
+    assert!(!event.matches_log(&topics, 31));
+    assert!(!event.matches_log(&topics[..2], 32));
+    assert!(!event.matches_log(&[B256::ZERO; 3], 32));
+    assert!(!event.matches_log(&[], 32));
+
+    let anonymous = Event {
+        anonymous: true,
+        ..event
+    };
+    assert!(anonymous.matches_log(&[B256::ZERO; 2], 64));
+    assert!(!anonymous.matches_log(&topics, 64));
+}
+
+#[test]
+fn topic_filter_spec() {
+    let event = Event::parse(
+        "Order(address indexed maker, uint256 amount, string indexed memo, \
+         (uint8,bytes32) indexed key)",
+    )
+    .unwrap();
+    assert_eq!(
+        event.topic_filter_spec(),
+        [
+            ("address".to_string(), false),
+            ("string".to_string(), true),
+            ("(uint8,bytes32)".to_string(), true),
+        ]
+    );
+    assert!(Event::parse("Empty(uint256)")
+        .unwrap()
+        .topic_filter_spec()
+        .is_empty());
+}
+
+#[test]
+fn parse_indexed_after_name() {
+    let before = Event::parse("Transfer(address indexed from, uint256 value)").unwrap();
+    let after = Event::parse("Transfer(address from indexed, uint256 value)").unwrap();
+    assert_eq!(after, before);
+    assert_eq!(after.inputs[0], eparam("from", "address", true));
+    assert_eq!(after.inputs[1], eparam("value", "uint256", false));
+
+    let event = Event::parse("Log(bytes memory data indexed)").unwrap();
+    assert_eq!(event.inputs[0], eparam("data", "bytes", true));
+    let event = Event::parse("Log(address indexed)").unwrap();
+    assert_eq!(event.inputs[0], eparam("", "address", true));
+
+    Event::parse("Transfer(address indexed from indexed)").unwrap_err();
+    Event::parse("Transfer(address indexed indexed)").unwrap_err();
+    Event::parse("Transfer(address from indexed indexed)").unwrap_err();
+    Function::parse("transfer(address to indexed)").unwrap_err();
+}
+
+#[test]
+fn selector_test_vectors() {
+    // topic0 as computed by `id(event.format())` in ethers.js
+    let vectors = [
+        (
+            "Transfer(address indexed from, address indexed to, uint256 value)",
+            "Transfer(address,address,uint256)",
+            alloy_primitives::b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"),
+        ),
+        (
+            "OrderFulfilled(bytes32 orderHash, address indexed offerer, address indexed zone, \
+             address recipient, (uint8 itemType, address token, uint256 identifier, uint256 \
+             amount)[] offer, (uint8 itemType, address token, uint256 identifier, uint256 \
+             amount, address recipient)[] consideration)",
+            "OrderFulfilled(bytes32,address,address,address,(uint8,address,uint256,uint256)[],(uint8,address,uint256,uint256,address)[])",
+            alloy_primitives::b256!("9d9af8e38d66c62e2c12f0225249fd9d721c54b83f48d9352c97c6cacdcb6f31"),
+        ),
+        (
+            "Swap(((address token, uint256 amount)[] legs, bytes32 id)[2] indexed routes, \
+             (bool ok, (string, bytes)[] data)[] results)",
+            "Swap(((address,uint256)[],bytes32)[2],(bool,(string,bytes)[])[])",
+            alloy_primitives::b256!("763180af938db465256f448e7f714ecafc139ba18c7a8aad02c9b7b760c11601"),
+        ),
+        (
+            "Nested((((uint8)[])[3]) indexed a, uint256[][] b) anonymous",
+            "Nested((((uint8)[])[3]),uint256[][])",
+            alloy_primitives::b256!("53bc08bb1cfea71b3c0bc1c8bc6211df805a19158e1a9d46374c6d1d54714a1f"),
+        ),
+    ];
+    for (s, signature, topic0) in vectors {
+        let event = Event::parse(s).unwrap();
+        assert_eq!(event.signature(), signature);
+        assert_eq!(event.selector(), topic0, "{signature}");
+
+        // round-trips through JSON `tuple`s and matches ethabi
+        let json = serde_json::to_string(&event).unwrap();
+        let from_json: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(from_json.selector(), topic0, "{signature}");
+        let ethabi: ethabi::Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(ethabi.signature().0, topic0.0, "{signature}");
+    }
+}
+
+#[test]
+fn anonymous_topics() {
+    let event = Event::parse(
+        "Log(address indexed a, bytes32 indexed b, uint256 indexed c, string indexed d) anonymous",
+    )
+    .unwrap();
+    assert!(!event.topic0_is_signature());
+    assert_eq!(
+        alloy_json_abi::AbiItem::from(&event).validate_spec(),
+        Ok(())
+    );
+
+    let layout = event.topic_layout();
+    assert_eq!(
+        layout.iter().map(|slot| slot.topic).collect::<Vec<_>>(),
+        [0, 1, 2, 3]
+    );
+    assert!(layout[3].hashed);
+    assert!(event.matches_log(&[B256::ZERO; 4], 0));
+    assert!(!event.matches_log(&[B256::ZERO; 5], 0));
+
+    let event = Event {
+        anonymous: false,
+        ..event
+    };
+    assert!(event.topic0_is_signature());
+    assert!(alloy_json_abi::AbiItem::from(&event)
+        .validate_spec()
+        .is_err());
+}
+
+#[test]
+fn verify_signature() {
+    let topic0 =
+        alloy_primitives::b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+    let candidates = [
+        "Approval(address indexed owner, address indexed spender, uint256 value)",
+        "Transfer(address indexed from, address indexed to, uint256 value)",
+        "Transfer(address,address)",
+    ];
+    let matches: Vec<_> = candidates
+        .iter()
+        .filter_map(|sig| Event::verify_signature(topic0, sig).ok())
+        .collect();
+    assert_eq!(matches, [Event::parse(candidates[1]).unwrap()]);
+
+    let err = Event::verify_signature(topic0, candidates[0]).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Approval(address,address,uint256)"),
+        "{err}"
+    );
+    Event::verify_signature(topic0, "Transfer(address,address,uint256) anonymous").unwrap_err();
+    Event::verify_signature(topic0, "Transfer(address,").unwrap_err();
+}
+
+#[test]
+fn data_params() {
+    let event = Event::parse(
+        "Swap(address indexed sender, uint256 amount0In, (uint112 a, bool b) info, address indexed to, string memo)",
+    )
+    .unwrap();
+    let data = event.data_params();
+    let fields: Vec<_> = data
+        .iter()
+        .map(|p| (p.name.as_str(), p.selector_type().into_owned()))
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            ("amount0In", "uint256".to_string()),
+            ("info", "(uint112,bool)".to_string()),
+            ("memo", "string".to_string()),
+        ]
+    );
+    assert_eq!(data[1].components[1].name, "b");
+
+    let all_indexed = Event::parse("Approval(address indexed owner)").unwrap();
+    assert!(all_indexed.data_params().is_empty());
+}
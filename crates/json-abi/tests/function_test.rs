@@ -0,0 +1,407 @@
+use alloy_json_abi::{AbiItem, Error, Event, Function, SignatureOptions};
+use std::collections::BTreeMap;
+
+#[test]
+fn db_signature() {
+    let f = Function::parse(
+        "fill( (address token, uint amount)[] orders, bytes32 salt, int[2] deltas ) returns (bool)",
+    )
+    .unwrap();
+    let sig = f.db_signature();
+    assert_eq!(sig, "fill((address,uint256)[],bytes32,int256[2])");
+    assert_eq!(sig, f.signature());
+    assert!(!sig.contains(' '));
+
+    let e = Error::parse("Bad( (uint a, (bool b) c) x )").unwrap();
+    assert_eq!(e.db_signature(), "Bad((uint256,(bool)))");
+    assert_eq!(e.db_signature(), e.signature());
+}
+
+#[test]
+fn try_to_human_readable() {
+    let function =
+        Function::parse("foo((uint256 a, bytes[] b)[2] x, bool) pure returns (uint256 c)").unwrap();
+    let s = function.try_to_human_readable().unwrap();
+    assert_eq!(
+        s,
+        "function foo((uint256 a, bytes[] b)[2] x, bool) pure returns (uint256 c)"
+    );
+    assert_eq!(AbiItem::parse(&s).unwrap(), AbiItem::from(&function));
+
+    let mut bad = function.clone();
+    bad.inputs[1].name = "not an identifier".into();
+    bad.try_to_human_readable().unwrap_err();
+
+    let mut bad = function.clone();
+    bad.outputs[0].ty = "uint256)".into();
+    bad.try_to_human_readable().unwrap_err();
+
+    let mut bad = function;
+    bad.name = "foo()".into();
+    bad.try_to_human_readable().unwrap_err();
+}
+
+#[test]
+fn overloaded_names() {
+    let items: Vec<_> = [
+        "function foo(uint256)",
+        "function foo(address)",
+        "function foo(bool)",
+        "function bar()",
+        "event bar()",
+        "error Baz()",
+        "error Baz(uint256)",
+    ]
+    .into_iter()
+    .map(|s| AbiItem::parse(s).unwrap())
+    .collect();
+    assert_eq!(
+        Function::overloaded_names(&items)
+            .into_iter()
+            .collect::<Vec<_>>(),
+        ["foo"]
+    );
+    assert!(Function::overloaded_names(&[]).is_empty());
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn to_fragment_json() {
+    let function =
+        Function::parse("transfer(address to, (uint256 a)[] b) view returns (bool)").unwrap();
+    assert_eq!(
+        function.to_fragment_json(),
+        r#"{"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"b","type":"tuple[]","components":[{"name":"a","type":"uint256"}]}],"outputs":[{"name":"","type":"bool"}],"stateMutability":"view"}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Function>(&function.to_fragment_json()).unwrap(),
+        function
+    );
+}
+
+#[test]
+fn is_state_changing() {
+    let functions = [
+        "balanceOf(address) view returns (uint256)",
+        "transfer(address,uint256) returns (bool)",
+        "deposit() payable",
+        "decimals() pure returns (uint8)",
+    ]
+    .map(|s| Function::parse(s).unwrap());
+    let (writes, reads): (Vec<&Function>, Vec<_>) =
+        functions.iter().partition(|f| f.is_state_changing());
+    assert_eq!(
+        writes.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+        ["transfer", "deposit"]
+    );
+    assert_eq!(
+        reads.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+        ["balanceOf", "decimals"]
+    );
+}
+
+#[test]
+fn input_offsets() {
+    let f = Function::parse("foo((uint256, bytes32[2]) a, uint8[] b, (bool, string) c, address d)")
+        .unwrap();
+    assert_eq!(f.input_offsets(), [0, 96, 128, 160]);
+    assert!(Function::parse("foo()").unwrap().input_offsets().is_empty());
+}
+
+#[test]
+fn signature_bytes() {
+    let f = Function::parse("transfer(address to, uint256 amount) returns (bool)").unwrap();
+    assert_eq!(f.signature_bytes(), b"transfer(address,uint256)");
+    assert_eq!(
+        alloy_primitives::keccak256(f.signature_bytes())[..4],
+        f.selector()
+    );
+
+    let e = Error::parse("Unauthorized(address caller)").unwrap();
+    assert_eq!(e.signature_bytes(), b"Unauthorized(address)");
+
+    let ev =
+        Event::parse("Transfer(address indexed from, address indexed to, uint256 value)").unwrap();
+    assert_eq!(ev.signature_bytes(), b"Transfer(address,address,uint256)");
+    assert_eq!(
+        alloy_primitives::keccak256(ev.signature_bytes()),
+        ev.selector()
+    );
+}
+
+#[test]
+fn fixed_point_types() {
+    let f = Function::parse(
+        "setRates(fixed128x18 a, ufixed128x18 b, fixed128x18[] c, ufixed128x18[2] d, fixed e, \
+         ufixed[] f)",
+    )
+    .unwrap();
+    assert_eq!(
+        f.signature(),
+        "setRates(fixed128x18,ufixed128x18,fixed128x18[],ufixed128x18[2],fixed128x18,ufixed128x18[])"
+    );
+    assert_eq!(
+        f.selector(),
+        alloy_primitives::keccak256(f.signature())[..4]
+    );
+
+    let e = Error::parse("Bad((fixed128x18,ufixed8x0)[] rates)").unwrap();
+    assert_eq!(e.signature(), "Bad((fixed128x18,ufixed8x0)[])");
+
+    Function::parse("f(fixed128x81)").unwrap_err();
+    Function::parse("f(ufixed7x18[])").unwrap_err();
+}
+
+#[test]
+fn signature_with_options() {
+    let f = Function::parse("fill((address maker, uint256[2] amounts)[] orders, bytes)").unwrap();
+    assert_eq!(
+        f.signature_with_options(SignatureOptions::default()),
+        f.signature()
+    );
+    assert_eq!(
+        f.signature_with_options(SignatureOptions {
+            separator: ";",
+            include_names: false
+        }),
+        "fill((address;uint256[2])[];bytes)"
+    );
+    assert_eq!(
+        f.signature_with_options(SignatureOptions {
+            separator: ", ",
+            include_names: true
+        }),
+        "fill((address maker, uint256[2] amounts)[] orders, bytes)"
+    );
+}
+
+#[test]
+fn selector_hex() {
+    let f = Function::parse("transfer(address to, uint256 amount)").unwrap();
+    assert_eq!(f.selector_hex(), "0xa9059cbb");
+    let e = Error::parse("Unauthorized()").unwrap();
+    assert_eq!(e.selector_hex(), "0x82b42900");
+}
+
+#[test]
+fn legacy_types() {
+    // harmless aliases are normalized to their canonical form
+    let f = Function::parse("f(byte a, byte[2] b, uint c, int[] d, fixed e, ufixed f)").unwrap();
+    assert_eq!(
+        f.signature(),
+        "f(bytes1,bytes1[2],uint256,int256[],fixed128x18,ufixed128x18)"
+    );
+
+    // invalid sizes are rejected
+    for ty in ["bytes0", "bytes33", "uint0", "uint7", "int264", "fixed7x18"] {
+        let err = Function::parse(&format!("f({ty})")).unwrap_err();
+        assert!(err.to_string().contains("invalid size"), "{ty}: {err}");
+    }
+
+    // JSON ABIs are ingested as-is, unless strict
+    let json = r#"{ "type": "function", "name": "f", "inputs": [{ "name": "a", "type": "bytes0" }], "outputs": [], "stateMutability": "nonpayable" }"#;
+    let f: Function = serde_json::from_str(json).unwrap();
+    assert_eq!(f.signature(), "f(bytes0)");
+    #[cfg(feature = "serde_json")]
+    {
+        let err = AbiItem::from_json_strict(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid type at inputs[0]: \"bytes0\""),
+            "{err}"
+        );
+    }
+}
+
+#[test]
+fn parse_tuple_array_outputs() {
+    let cases = [
+        (
+            "getOrders() view returns ((address maker, uint256 amount)[] orders)",
+            "getOrders()((address,uint256)[])",
+        ),
+        (
+            "getRanges() returns (((uint256,uint256)[2]) ranges)",
+            "getRanges()(((uint256,uint256)[2]))",
+        ),
+        (
+            "f() returns ((address a, uint256 b)[] memory out, ((uint256,uint256)[2])[] x)",
+            "f()((address,uint256)[],((uint256,uint256)[2])[])",
+        ),
+        (
+            "f() view returns (tuple(address,uint256)[] , bool)",
+            "f()((address,uint256)[],bool)",
+        ),
+        (
+            "f() pure returns ((uint256,(bool,bytes)[])[3][] nested)",
+            "f()((uint256,(bool,bytes)[])[3][])",
+        ),
+    ];
+    for (s, expected) in cases {
+        let f = Function::parse(s).unwrap();
+        assert_eq!(f.signature_full(), expected, "{s}");
+        // the canonical form parses back to itself
+        let canonical = expected.replacen(")(", ") returns (", 1);
+        assert_eq!(
+            Function::parse(&canonical).unwrap().signature_full(),
+            expected
+        );
+    }
+
+    let f = Function::parse("f() returns ((address a, uint256 b)[2] pairs)").unwrap();
+    assert_eq!(f.outputs[0].ty, "tuple[2]");
+    assert_eq!(f.outputs[0].name, "pairs");
+    assert_eq!(f.outputs[0].components[1].name, "b");
+
+    Function::parse("f() returns ((address,uint256)[)").unwrap_err();
+    Function::parse("f() returns ((address,uint256) [2] x y)").unwrap_err();
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn abi_dispatch() {
+    use alloy_json_abi::AbiDispatch;
+    use std::collections::HashMap;
+
+    let functions = ["transfer(address to, uint256 amount)", "totalSupply()"]
+        .map(|s| Function::parse(s).unwrap());
+    let hash_map: HashMap<_, _> = functions
+        .iter()
+        .map(|f| (f.selector(), f.clone()))
+        .collect();
+    let btree_map: BTreeMap<_, _> = functions
+        .iter()
+        .map(|f| (f.selector(), f.clone()))
+        .collect();
+
+    let mut calldata = functions[0].selector().to_vec();
+    calldata.extend([0u8; 64]);
+    let (function, args) = hash_map.dispatch(&calldata).unwrap();
+    assert_eq!(function, &functions[0]);
+    assert_eq!(args.len(), 64);
+    assert_eq!(btree_map.dispatch(&calldata), Some((function, args)));
+
+    let selector = functions[1].selector();
+    let (function, args) = hash_map.dispatch(&selector[..]).unwrap();
+    assert_eq!(function.name, "totalSupply");
+    assert!(args.is_empty());
+
+    assert!(hash_map.dispatch(&calldata[..3]).is_none());
+    assert!(hash_map.dispatch(&[0, 0, 0, 0]).is_none());
+}
+
+#[test]
+fn error_parse_with_registry() {
+    let registry = BTreeMap::from([
+        (
+            "Point".to_string(),
+            Function::parse("f(uint256 x, uint256 y)").unwrap().inputs,
+        ),
+        (
+            "Segment".to_string(),
+            Function::parse("f(Point start, Point end)").unwrap().inputs,
+        ),
+    ]);
+
+    let e = Error::parse_with_registry("OutOfBounds(Point p, Segment[2] s, bool b)", &registry)
+        .unwrap();
+    assert_eq!(
+        e.signature(),
+        "OutOfBounds((uint256,uint256),((uint256,uint256),(uint256,uint256))[2],bool)"
+    );
+    assert_eq!(e.inputs[0].ty, "tuple");
+    assert_eq!(
+        e.inputs[0].internal_type.as_ref().unwrap().struct_name(),
+        Some("Point")
+    );
+    assert_eq!(e.inputs[1].ty, "tuple[2]");
+    assert_eq!(
+        e.inputs[1].internal_type.as_ref().unwrap().struct_name(),
+        Some("Segment")
+    );
+    assert_eq!(e.inputs[1].components[0].components[1].name, "y");
+    assert_eq!(e.inputs[2].internal_type, None);
+
+    // unregistered names are left as-is
+    let e = Error::parse_with_registry("Bad(Unknown u)", &registry).unwrap();
+    assert_eq!(e.signature(), "Bad(Unknown)");
+
+    // self-referential structs are rejected
+    let cyclic = BTreeMap::from([(
+        "Node".to_string(),
+        Function::parse("f(Node next)").unwrap().inputs,
+    )]);
+    Error::parse_with_registry("Bad(Node n)", &cyclic).unwrap_err();
+}
+
+#[test]
+fn function_error_collisions() {
+    let items: Vec<_> = [
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "function approve(address spender, uint256 amount) returns (bool)",
+        "error many_msg_babbage(bytes1)",
+        "error func_2093253501(bytes)",
+        "error Unauthorized()",
+        "event transfer(address to, uint256 amount)",
+    ]
+    .iter()
+    .map(|s| AbiItem::parse(s).unwrap())
+    .collect();
+
+    let selector = Function::parse("transfer(address,uint256)")
+        .unwrap()
+        .selector();
+    assert_eq!(
+        Function::function_error_collisions(&items),
+        [
+            (
+                selector,
+                "transfer(address,uint256)".to_string(),
+                "func_2093253501(bytes)".to_string()
+            ),
+            (
+                selector,
+                "transfer(address,uint256)".to_string(),
+                "many_msg_babbage(bytes1)".to_string()
+            ),
+        ]
+    );
+    assert!(Function::function_error_collisions(&items[1..]).is_empty());
+}
+
+#[test]
+fn inputs_signature() {
+    let f = Function::parse("transfer(address to, uint256 amount) returns (bool)").unwrap();
+    assert_eq!(f.inputs_signature(), "(address,uint256)");
+    assert_eq!(format!("{}{}", f.name, f.inputs_signature()), f.signature());
+
+    let g = Function::parse("send((address,uint256)[] batch, bytes data)").unwrap();
+    assert_eq!(g.inputs_signature(), "((address,uint256)[],bytes)");
+    let h = Function::parse("totalSupply() view returns (uint256)").unwrap();
+    assert_eq!(h.inputs_signature(), "()");
+}
+
+#[test]
+fn mutability_changed() {
+    use alloy_json_abi::StateMutability::*;
+
+    let old = Function::parse("deposit(uint256 amount)").unwrap();
+    let payable = Function::parse("deposit(uint256 value) payable").unwrap();
+    assert_eq!(
+        old.mutability_changed(&payable),
+        Some((NonPayable, Payable))
+    );
+    assert_eq!(
+        payable.mutability_changed(&old),
+        Some((Payable, NonPayable))
+    );
+
+    let view = Function::parse("total() view returns (uint256)").unwrap();
+    let pure = Function::parse("total() pure returns (uint256)").unwrap();
+    assert_eq!(view.mutability_changed(&pure), Some((View, Pure)));
+    assert_eq!(view.mutability_changed(&view), None);
+
+    // different selectors are not compared
+    let other = Function::parse("deposit(uint128 amount) payable").unwrap();
+    assert_eq!(old.mutability_changed(&other), None);
+}
@@ -1,7 +1,7 @@
 mod test_helpers;
 
 use alloy_json_abi::{
-    Constructor, Error, Event, EventParam, Fallback, Function, JsonAbi, Param, Receive,
+    AbiItem, Constructor, Error, Event, EventParam, Fallback, Function, JsonAbi, Param, Receive,
     StateMutability,
 };
 use std::collections::BTreeMap;
@@ -601,3 +601,248 @@ fn fallback() {
 
     assert_ser_de!(JsonAbi, deserialized);
 }
+
+#[test]
+fn from_iter_collisions() {
+    let abi: JsonAbi = [
+        "constructor(uint256)",
+        "function burn(uint256 amount)",
+        "function collate_propagate_storage(bytes16)",
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+        "error Unauthorized()",
+    ]
+    .into_iter()
+    .map(|s| AbiItem::parse(s).unwrap())
+    .collect();
+    assert_eq!(abi.len(), 6);
+    assert!(abi.constructor.is_some());
+
+    let collisions = abi.collisions();
+    assert_eq!(collisions.len(), 1);
+    let (selector, items) = &collisions[0];
+    assert_eq!(*selector, [0x42, 0x96, 0x6c, 0x68]);
+    let names: Vec<_> = items
+        .iter()
+        .map(|item| item.name().unwrap().as_str())
+        .collect();
+    assert_eq!(names, ["burn", "collate_propagate_storage"]);
+
+    let mut abi: JsonAbi = abi
+        .items()
+        .filter(|item| item.name().map_or(true, |n| n != "burn"))
+        .collect();
+    assert!(abi.collisions().is_empty());
+
+    // collisions reflect the current items
+    let burn = Function::parse("burn(uint256 amount)").unwrap();
+    abi.functions
+        .entry(burn.name.clone())
+        .or_default()
+        .push(burn);
+    assert_eq!(abi.collisions().len(), 1);
+}
+
+#[test]
+fn function_by_signature() {
+    let abi: JsonAbi = [
+        "function foo(uint256 a) returns (bool)",
+        "function foo(address)",
+        "function foo((uint256,bool)[])",
+        "function bar()",
+    ]
+    .into_iter()
+    .map(|s| AbiItem::parse(s).unwrap())
+    .collect();
+
+    let foo = abi.function_by_signature("foo(uint)").unwrap();
+    assert_eq!(foo.signature(), "foo(uint256)");
+    assert_eq!(foo.inputs[0].name, "a");
+    assert_eq!(
+        abi.function_by_signature("foo(address)")
+            .unwrap()
+            .signature(),
+        "foo(address)"
+    );
+    assert_eq!(
+        abi.function_by_signature("foo((uint256 x, bool y)[])")
+            .unwrap()
+            .signature(),
+        "foo((uint256,bool)[])"
+    );
+    assert!(abi.function_by_signature("foo(bool)").is_none());
+    assert!(abi.function_by_signature("baz()").is_none());
+    assert!(abi.function_by_signature("foo(").is_none());
+}
+
+#[test]
+fn items_by_name() {
+    let abi: JsonAbi = [
+        "function foo(uint256)",
+        "function foo(address)",
+        "function bar()",
+        "event Foo(uint256 indexed)",
+        "event Foo(address indexed)",
+    ]
+    .into_iter()
+    .map(|s| AbiItem::parse(s).unwrap())
+    .collect();
+
+    let foos: Vec<_> = abi
+        .functions_by_name("foo")
+        .iter()
+        .map(|f| f.signature())
+        .collect();
+    assert_eq!(foos, ["foo(uint256)", "foo(address)"]);
+    assert_eq!(abi.functions_by_name("bar").len(), 1);
+    assert!(abi.functions_by_name("Foo").is_empty());
+
+    assert_eq!(abi.events_by_name("Foo").len(), 2);
+    assert!(abi.events_by_name("foo").is_empty());
+}
+
+#[test]
+fn selector_table() {
+    let abi: JsonAbi = [
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "function burn(uint256 amount)",
+        "function collate_propagate_storage(bytes16)",
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+        "error Unauthorized()",
+    ]
+    .into_iter()
+    .map(|s| AbiItem::parse(s).unwrap())
+    .collect();
+
+    let table = abi.selector_table();
+    assert_eq!(table.len(), 4);
+    assert!(table.windows(2).all(|w| w[0] <= w[1]));
+    let signatures: Vec<_> = table.iter().map(|(_, s)| s.as_str()).collect();
+    assert_eq!(
+        signatures,
+        [
+            "burn(uint256)",
+            "collate_propagate_storage(bytes16)",
+            "Unauthorized()",
+            "transfer(address,uint256)",
+        ]
+    );
+    for (selector, signature) in &table {
+        assert_eq!(*selector, alloy_primitives::keccak256(signature)[..4]);
+    }
+}
+
+#[test]
+fn strip_internal_types() {
+    let json = r#"[
+        {
+            "type": "constructor",
+            "inputs": [{ "name": "token", "type": "address", "internalType": "contract IERC20" }],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "function",
+            "name": "fill",
+            "inputs": [
+                {
+                    "name": "order",
+                    "type": "tuple",
+                    "internalType": "struct Lib.Order",
+                    "components": [
+                        { "name": "maker", "type": "address", "internalType": "address" }
+                    ]
+                }
+            ],
+            "outputs": [{ "name": "", "type": "uint8", "internalType": "enum Lib.Status" }],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "event",
+            "name": "Filled",
+            "inputs": [
+                { "name": "order", "type": "bytes32", "indexed": true, "internalType": "bytes32" }
+            ],
+            "anonymous": false
+        },
+        {
+            "type": "error",
+            "name": "Expired",
+            "inputs": [{ "name": "at", "type": "uint256", "internalType": "uint256" }]
+        }
+    ]"#;
+    let mut abi: JsonAbi = serde_json::from_str(json).unwrap();
+    let original = abi.clone();
+    abi.strip_internal_types();
+    assert_ne!(abi, original);
+    assert_eq!(abi.len(), original.len());
+
+    let stripped = serde_json::to_string(&abi).unwrap();
+    assert!(!stripped.contains("internalType"), "{stripped}");
+    let fill = &abi.functions["fill"][0];
+    assert_eq!(fill.inputs[0].components[0].name, "maker");
+    assert_eq!(fill.selector(), original.functions["fill"][0].selector());
+}
+
+#[test]
+fn remove_by_selector() {
+    let mut abi: JsonAbi = [
+        "function burn(uint256 amount)",
+        "function collate_propagate_storage(bytes16)",
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "function transfer(address to)",
+        "error Unauthorized()",
+    ]
+    .into_iter()
+    .map(|s| AbiItem::parse(s).unwrap())
+    .collect();
+
+    let removed = abi.remove_by_selector([0x42, 0x96, 0x6c, 0x68].into());
+    let names: Vec<_> = removed
+        .iter()
+        .map(|item| item.name().unwrap().as_str())
+        .collect();
+    assert_eq!(names, ["burn", "collate_propagate_storage"]);
+    assert!(abi.function("burn").is_none());
+    assert!(abi.function("collate_propagate_storage").is_none());
+
+    let transfer = Function::parse("transfer(address,uint256)").unwrap();
+    let removed = abi.remove_by_selector(transfer.selector());
+    assert_eq!(removed.len(), 1);
+    assert_eq!(abi.function("transfer").unwrap().len(), 1);
+
+    let unauthorized = Error::parse("Unauthorized()").unwrap();
+    assert_eq!(abi.remove_by_selector(unauthorized.selector()).len(), 1);
+    assert!(abi.errors.is_empty());
+
+    assert!(abi.remove_by_selector(unauthorized.selector()).is_empty());
+    assert_eq!(abi.len(), 1);
+}
+
+#[test]
+fn filter() {
+    let abi: JsonAbi = [
+        "constructor(address owner)",
+        "function balanceOf(address owner) view returns (uint256)",
+        "function balanceOf(address owner, uint256 id) view returns (uint256)",
+        "function transfer(address to, uint256 amount) returns (bool)",
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+        "error Unauthorized()",
+    ]
+    .into_iter()
+    .map(|s| AbiItem::parse(s).unwrap())
+    .collect();
+
+    let views = abi.filter(|item| item.state_mutability() == Some(StateMutability::View));
+    assert_eq!(views.len(), 2);
+    assert_eq!(views.function("balanceOf").unwrap().len(), 2);
+    assert!(views.function("transfer").is_none());
+    assert!(views.constructor.is_none());
+    assert!(views.events.is_empty() && views.errors.is_empty());
+
+    let events = abi.filter(|item| matches!(item, AbiItem::Event(_)));
+    assert_eq!(events.len(), 1);
+    assert!(events.event("Transfer").is_some());
+
+    assert_eq!(abi.filter(|_| true), abi);
+    assert!(abi.filter(|_| false).is_empty());
+}
@@ -1,6 +1,7 @@
 mod test_helpers;
 
 use alloy_json_abi::{InternalType::Struct, Param};
+use std::collections::BTreeMap;
 
 #[test]
 fn param_simple() {
@@ -442,3 +443,395 @@ fn param_tuple_with_nested_tuple_arrays() {
 
     assert_json_eq!(s, serde_json::to_string(&deserialized).unwrap().as_str());
 }
+
+#[test]
+fn param_components_require_tuple() {
+    let component = r#"[{ "name": "a", "type": "address" }]"#;
+    for ty in [
+        "uint256",
+        "uint256[]",
+        "tuplex",
+        "tuple[",
+        "tuple[2]x",
+        "(address)",
+    ] {
+        let s = format!(r#"{{ "name": "foo", "type": "{ty}", "components": {component} }}"#);
+        let err = serde_json::from_str::<Param>(&s).unwrap_err().to_string();
+        assert!(
+            err.contains("since the parameter has components"),
+            "{ty}: {err}"
+        );
+    }
+
+    for ty in ["tuple", "tuple[]", "tuple[2][]"] {
+        let s = format!(r#"{{ "name": "foo", "type": "{ty}", "components": {component} }}"#);
+        let param = serde_json::from_str::<Param>(&s).unwrap();
+        assert_eq!(param.ty, ty);
+        assert_eq!(param.components.len(), 1);
+    }
+}
+
+#[test]
+fn param_abi_eq() {
+    let with_internal: Param = serde_json::from_str(
+        r#"{
+            "name": "foo",
+            "type": "tuple",
+            "internalType": "struct Foo",
+            "components": [
+                { "name": "a", "type": "address", "internalType": "contract IERC20" }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let mut without_internal: Param = serde_json::from_str(
+        r#"{
+            "name": "foo",
+            "type": "tuple",
+            "components": [{ "name": "a", "type": "address" }]
+        }"#,
+    )
+    .unwrap();
+
+    assert_ne!(with_internal, without_internal);
+    assert!(with_internal.abi_eq(&without_internal));
+
+    without_internal.components[0].name = "b".into();
+    assert!(!with_internal.abi_eq(&without_internal));
+}
+
+#[test]
+fn param_resolve_components() {
+    let leaf = |name: &str, ty: &str| Param {
+        name: name.into(),
+        ty: ty.into(),
+        components: vec![],
+        internal_type: None,
+    };
+    let strukt = |name: &str, ty: &str, it: &str| Param {
+        internal_type: Some(Struct {
+            contract: None,
+            ty: it.into(),
+        }),
+        ..leaf(name, ty)
+    };
+
+    let mut registry = BTreeMap::new();
+    registry.insert(
+        "Outer".to_string(),
+        vec![leaf("a", "uint256"), strukt("inner", "tuple", "Inner")],
+    );
+    registry.insert("Inner".to_string(), vec![leaf("b", "address")]);
+    registry.insert("Loop".to_string(), vec![strukt("l", "tuple", "Loop")]);
+
+    let mut param = strukt("x", "tuple[]", "Outer[]");
+    param.resolve_components(&registry);
+    assert_eq!(param.selector_type(), "(uint256,(address))[]");
+
+    let mut param = strukt("x", "tuple", "Missing");
+    param.resolve_components(&registry);
+    assert!(param.components.is_empty());
+
+    let mut param = strukt("x", "tuple", "Loop");
+    param.resolve_components(&registry);
+    assert_eq!(param.components.len(), 1);
+    assert!(param.components[0].components.is_empty());
+}
+
+#[test]
+fn param_encoded_size_bounds() {
+    let param = |ty: &str, components: Vec<Param>| Param {
+        name: String::new(),
+        ty: ty.into(),
+        components,
+        internal_type: None,
+    };
+    let leaf = |ty: &str| param(ty, vec![]);
+
+    assert_eq!(leaf("uint256").encoded_size_bounds(), (32, Some(32)));
+    assert_eq!(leaf("address[3]").encoded_size_bounds(), (96, Some(96)));
+    assert_eq!(leaf("bool[2][3]").encoded_size_bounds(), (192, Some(192)));
+    assert_eq!(leaf("bytes").encoded_size_bounds(), (32, None));
+    assert_eq!(leaf("uint256[]").encoded_size_bounds(), (32, None));
+    assert_eq!(leaf("string[2]").encoded_size_bounds(), (128, None));
+    assert_eq!(leaf("not a type").encoded_size_bounds(), (0, None));
+
+    let static_tuple = param("tuple[2]", vec![leaf("uint8"), leaf("bytes32[2]")]);
+    assert_eq!(static_tuple.encoded_size_bounds(), (192, Some(192)));
+
+    let dynamic_tuple = param("tuple", vec![leaf("uint8"), leaf("bytes")]);
+    assert_eq!(dynamic_tuple.encoded_size_bounds(), (96, None));
+    let nested = param(
+        "tuple",
+        vec![static_tuple, param("tuple[]", vec![leaf("bool")])],
+    );
+    assert_eq!(nested.encoded_size_bounds(), (256, None));
+}
+
+#[test]
+fn param_null_components() {
+    let param: Param =
+        serde_json::from_str(r#"{ "name": "a", "type": "uint256", "components": null }"#).unwrap();
+    assert_eq!(param.ty, "uint256");
+    assert!(param.components.is_empty());
+    assert_eq!(
+        serde_json::to_string(&param).unwrap(),
+        r#"{"name":"a","type":"uint256"}"#
+    );
+
+    let param: alloy_json_abi::EventParam = serde_json::from_str(
+        r#"{ "name": "a", "type": "bool", "indexed": true, "components": null }"#,
+    )
+    .unwrap();
+    assert!(param.components.is_empty());
+}
+
+#[test]
+fn param_canonical_with_names() {
+    let function = alloy_json_abi::Function::parse(
+        "foo((address token, (uint256 a, bool)[2] amounts)[] orders, uint8 x)",
+    )
+    .unwrap();
+    assert_eq!(
+        function.inputs[0].canonical_with_names(),
+        "(address token, (uint256 a, bool)[2] amounts)[]"
+    );
+    assert_eq!(
+        function.inputs[0].selector_type(),
+        "(address,(uint256,bool)[2])[]"
+    );
+    assert_eq!(function.inputs[1].canonical_with_names(), "uint8");
+}
+
+#[test]
+fn param_from_type_specifier() {
+    use alloy_sol_type_parser::TypeSpecifier;
+
+    let spec = TypeSpecifier::parse("uint256[][3]").unwrap();
+    let param = Param::from_type_specifier("a", &spec).unwrap();
+    assert_eq!(param.name, "a");
+    assert_eq!(param.ty, "uint256[][3]");
+    assert!(param.components.is_empty());
+
+    let spec = TypeSpecifier::parse("((address,bytes)[],uint8)").unwrap();
+    let param = Param::from_type_specifier("b", &spec).unwrap();
+    assert_eq!(param.ty, "tuple");
+    assert_eq!(param.components[0].ty, "tuple[]");
+    assert_eq!(param.components[0].components[1].ty, "bytes");
+    assert_eq!(param.selector_type(), spec.span());
+
+    // aliases are normalized as when parsing a signature
+    for (ty, canonical) in [
+        ("uint", "uint256"),
+        ("int[]", "int256[]"),
+        ("byte", "bytes1"),
+        ("byte[2]", "bytes1[2]"),
+        ("fixed[][1]", "fixed128x18[][1]"),
+    ] {
+        let spec = TypeSpecifier::parse(ty).unwrap();
+        let param = Param::from_type_specifier("x", &spec).unwrap();
+        assert_eq!(param.ty, canonical);
+        let f = alloy_json_abi::Function::parse(&format!("f({ty} x)")).unwrap();
+        assert_eq!(f.inputs[0], param);
+    }
+    let spec = TypeSpecifier::parse("(uint,byte[])").unwrap();
+    let param = Param::from_type_specifier("", &spec).unwrap();
+    assert_eq!(param.selector_type(), "(uint256,bytes1[])");
+
+    let spec = TypeSpecifier::parse("(uint7,bool)").unwrap();
+    Param::from_type_specifier("", &spec).unwrap_err();
+}
+
+#[test]
+fn param_to_inline_type() {
+    let param = Param {
+        name: "e".into(),
+        ty: "tuple[]".into(),
+        components: vec![
+            Param {
+                name: "a".into(),
+                ty: "address".into(),
+                components: vec![],
+                internal_type: None,
+            },
+            Param {
+                name: "d".into(),
+                ty: "tuple[2]".into(),
+                components: vec![
+                    Param {
+                        name: "b".into(),
+                        ty: "uint256".into(),
+                        components: vec![],
+                        internal_type: None,
+                    },
+                    Param {
+                        name: "c".into(),
+                        ty: "tuple".into(),
+                        components: vec![],
+                        internal_type: None,
+                    },
+                ],
+                internal_type: None,
+            },
+        ],
+        internal_type: None,
+    };
+    assert_eq!(param.to_inline_type(), "(address,(uint256,())[2])[]");
+    assert_eq!(param.selector_type(), "(address,(uint256,tuple)[2])[]");
+    assert_eq!(param.components[0].to_inline_type(), "address");
+}
+
+#[test]
+fn param_components_accessors() {
+    let f = alloy_json_abi::Function::parse("f((uint256 a, (bool b) c) x, address y)").unwrap();
+    let mut x = f.inputs[0].clone();
+    assert_eq!(x.components().len(), 2);
+    assert_eq!(x.components()[1].components()[0].name, "b");
+    assert!(f.inputs[1].components().is_empty());
+
+    x.components_mut()[0].name = "renamed".into();
+    x.components_mut()[1].ty = "uint8".into();
+    x.components_mut()[1].components.clear();
+    assert_eq!(x.selector_type(), "(uint256,uint8)");
+    assert_eq!(x.components()[0].name, "renamed");
+}
+
+#[test]
+fn param_max_nesting_depth() {
+    fn nested(depth: usize) -> String {
+        let mut json = r#"{ "name": "", "type": "uint256" }"#.to_string();
+        for _ in 0..depth {
+            json = format!(r#"{{ "name": "", "type": "tuple", "components": [{json}] }}"#);
+        }
+        json
+    }
+
+    let param: Param = serde_json::from_str(&nested(Param::MAX_NESTING_DEPTH)).unwrap();
+    assert_eq!(param.clone(), param);
+    let err = serde_json::from_str::<Param>(&nested(Param::MAX_NESTING_DEPTH + 1)).unwrap_err();
+    assert!(err.to_string().contains("nested deeper than"), "{err}");
+
+    let event_param = format!(
+        r#"{{ "name": "", "type": "tuple", "indexed": true, "components": [{}] }}"#,
+        nested(Param::MAX_NESTING_DEPTH)
+    );
+    serde_json::from_str::<alloy_json_abi::EventParam>(&event_param).unwrap_err();
+}
+
+#[test]
+fn param_normalize_type() {
+    let address = Param {
+        name: "maker".into(),
+        ty: "address ".into(),
+        components: vec![],
+        internal_type: None,
+    };
+    let mut param = Param {
+        name: "order".into(),
+        ty: " tuple[] ".into(),
+        components: vec![
+            address.clone(),
+            Param {
+                name: "amount".into(),
+                ty: "\tuint256".into(),
+                components: vec![],
+                internal_type: None,
+            },
+        ],
+        internal_type: None,
+    };
+    assert_ne!(param.selector_type(), "(address,uint256)[]");
+    param.normalize_type().unwrap();
+    assert_eq!(param.ty, "tuple[]");
+    assert_eq!(param.components[0].ty, "address");
+    assert_eq!(param.components[1].ty, "uint256");
+    assert_eq!(param.selector_type(), "(address,uint256)[]");
+
+    let mut param = Param {
+        ty: "uint256 x".into(),
+        ..Default::default()
+    };
+    param.normalize_type().unwrap_err();
+    let mut param = Param {
+        ty: "bool".into(),
+        components: vec![address],
+        ..Default::default()
+    };
+    param.normalize_type().unwrap_err();
+}
+
+#[test]
+fn param_rejects_partial_types() {
+    for ty in [
+        "uint256[",
+        "uint256]",
+        "uint256[][",
+        "uint256[]]",
+        "uint256[2",
+        "uint256[x]",
+        "uint256[2]x",
+        "(uint256)[",
+        "(uint256)]",
+    ] {
+        let err = alloy_json_abi::Function::parse(&format!("f({ty})")).unwrap_err();
+        // the parser only reports its input with `std`
+        if cfg!(feature = "std") {
+            assert!(err.to_string().contains(ty), "{ty}: {err}");
+        }
+
+        let json = format!(r#"{{ "name": "", "type": "{ty}" }}"#);
+        let err = serde_json::from_str::<Param>(&json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected a valid Solidity type specifier"),
+            "{ty}: {err}"
+        );
+    }
+
+    for ty in ["tuple[", "tuple]", "tuple[][", "tuple[2]x"] {
+        let json = format!(
+            r#"{{ "name": "", "type": "{ty}", "components": [{{ "name": "", "type": "bool" }}] }}"#
+        );
+        serde_json::from_str::<Param>(&json).unwrap_err();
+    }
+}
+
+#[test]
+fn param_nesting_depth() {
+    let f = alloy_json_abi::Function::parse(
+        "f(uint256 a, (bool, address) b, ((uint8)[], bytes) c, (((string)))[2] d)",
+    )
+    .unwrap();
+    let depths: Vec<_> = f.inputs.iter().map(Param::nesting_depth).collect();
+    assert_eq!(depths, [0, 1, 2, 3]);
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn param_to_json_value() {
+    let json = r#"{
+        "name": "order",
+        "type": "tuple[]",
+        "internalType": "struct Exchange.Order[]",
+        "components": [
+            { "name": "maker", "type": "address", "internalType": "address" },
+            { "name": "amount", "type": "uint256" }
+        ]
+    }"#;
+    let param: Param = serde_json::from_str(json).unwrap();
+    let value = param.to_json_value();
+    assert_eq!(
+        value,
+        serde_json::from_str::<serde_json::Value>(json).unwrap()
+    );
+    assert_eq!(
+        serde_json::from_str::<Param>(&value.to_string()).unwrap(),
+        param
+    );
+
+    let scalar = param.components()[1].to_json_value();
+    assert_eq!(
+        scalar,
+        serde_json::json!({ "name": "amount", "type": "uint256" })
+    );
+}
@@ -0,0 +1,379 @@
+use alloy_json_abi::{
+    AbiItem, Constructor, Error, Event, EventParam, Fallback, Function, InternalType, Param,
+    Receive, SpecViolation, StateMutability,
+};
+use std::collections::BTreeMap;
+
+fn param(name: &str, ty: &str) -> Param {
+    Param {
+        name: name.into(),
+        ty: ty.into(),
+        components: vec![],
+        internal_type: None,
+    }
+}
+
+fn eparam(name: &str, ty: &str, indexed: bool) -> EventParam {
+    EventParam {
+        name: name.into(),
+        ty: ty.into(),
+        indexed,
+        components: vec![],
+        internal_type: None,
+    }
+}
+
+#[test]
+fn parse_function() {
+    assert_eq!(
+        Function::parse("transfer(address to, uint amount) external returns (bool)"),
+        Ok(Function {
+            name: "transfer".into(),
+            inputs: vec![param("to", "address"), param("amount", "uint256")],
+            outputs: vec![param("", "bool")],
+            state_mutability: StateMutability::NonPayable,
+        })
+    );
+
+    let f = Function::parse("foo((uint256 a, bytes[] b)[2] memory x, tuple(bool) y) pure").unwrap();
+    assert_eq!(f.signature(), "foo((uint256,bytes[])[2],(bool))");
+    assert_eq!(f.state_mutability, StateMutability::Pure);
+    assert_eq!(f.inputs[0].ty, "tuple[2]");
+    assert_eq!(f.inputs[0].components[1], param("b", "bytes[]"));
+
+    Function::parse("foo(").unwrap_err();
+    Function::parse("foo(uint256 a b)").unwrap_err();
+    Function::parse("foo(uint256) returns").unwrap_err();
+    Function::parse("foo() internal").unwrap_err();
+    Function::parse("1foo()").unwrap_err();
+}
+
+#[test]
+fn parse_event() {
+    assert_eq!(
+        Event::parse("Transfer(address indexed from, address indexed to, uint256 value)"),
+        Ok(Event {
+            name: "Transfer".into(),
+            inputs: vec![
+                eparam("from", "address", true),
+                eparam("to", "address", true),
+                eparam("value", "uint256", false),
+            ],
+            anonymous: false,
+        })
+    );
+    assert!(Event::parse("Foo(bool) anonymous").unwrap().anonymous);
+    Event::parse("Foo(bool) external").unwrap_err();
+}
+
+#[test]
+fn parse_error() {
+    assert_eq!(
+        Error::parse("Unauthorized(address caller)"),
+        Ok(Error {
+            name: "Unauthorized".into(),
+            inputs: vec![param("caller", "address")],
+        })
+    );
+    Error::parse("Unauthorized(address indexed caller)").unwrap_err();
+    Error::parse("Unauthorized() view").unwrap_err();
+}
+
+#[test]
+fn parse_item() {
+    assert_eq!(
+        AbiItem::parse("constructor(string name) payable"),
+        Ok(Constructor {
+            inputs: vec![param("name", "string")],
+            state_mutability: StateMutability::Payable,
+        }
+        .into())
+    );
+    assert_eq!(
+        AbiItem::parse("fallback() external"),
+        Ok(Fallback {
+            state_mutability: StateMutability::NonPayable
+        }
+        .into())
+    );
+    assert_eq!(
+        AbiItem::parse("receive() external payable"),
+        Ok(Receive {
+            state_mutability: StateMutability::Payable
+        }
+        .into())
+    );
+    assert_eq!(
+        AbiItem::parse("function balanceOf(address) view returns (uint256)")
+            .unwrap()
+            .debug_name(),
+        "Function"
+    );
+    AbiItem::parse("receive(uint256)").unwrap_err();
+    AbiItem::parse("balanceOf(address)").unwrap_err();
+}
+
+#[test]
+fn parse_human_readable_lossy() {
+    let abi = "
+function balanceOf(address owner) view returns (uint256)
+function transfer(address to, uint256 amount
+
+event Transfer(address indexed from, address indexed to, uint256 value)
+struct Foo { uint256 a; }
+error Unauthorized()
+";
+    let (items, errors) = AbiItem::parse_human_readable_lossy(abi);
+    let names: Vec<_> = items
+        .iter()
+        .map(|item| item.name().unwrap().as_str())
+        .collect();
+    assert_eq!(names, ["balanceOf", "Transfer", "Unauthorized"]);
+    let lines: Vec<_> = errors.iter().map(|(line, _)| *line).collect();
+    assert_eq!(lines, [3, 6]);
+}
+
+#[test]
+fn fmt_human_readable_abi() {
+    let abi = "\
+error Unauthorized(address caller)
+event Transfer(address indexed from, address indexed to, uint256 value)
+event Anon(bytes32 indexed) anonymous
+function transfer(address to, uint256 amount) returns (bool)
+function foo((uint256 a, bool[] b)[2] x) payable
+fallback() external
+receive() external payable
+constructor(string name) payable
+";
+    let (items, errors) = AbiItem::parse_human_readable_lossy(abi);
+    assert!(errors.is_empty());
+    let formatted = AbiItem::fmt_human_readable_abi(&items).unwrap();
+    assert_eq!(
+        formatted,
+        "\
+constructor(string name) payable
+receive() external payable
+fallback() external
+function transfer(address to, uint256 amount) returns (bool)
+function foo((uint256 a, bool[] b)[2] x) payable
+event Transfer(address indexed from, address indexed to, uint256 value)
+event Anon(bytes32 indexed) anonymous
+error Unauthorized(address caller)
+"
+    );
+
+    let (reparsed, errors) = AbiItem::parse_human_readable_lossy(&formatted);
+    assert!(errors.is_empty());
+    assert_eq!(reparsed.len(), items.len());
+    for item in &items {
+        assert!(reparsed.contains(item), "{item:?}");
+    }
+}
+
+#[test]
+fn parse_event_unnamed_indexed() {
+    let event =
+        Event::parse("Transfer(address indexed, address indexed to, uint256, bytes32 indexed id)")
+            .unwrap();
+    assert_eq!(
+        event.inputs,
+        [
+            eparam("", "address", true),
+            eparam("to", "address", true),
+            eparam("", "uint256", false),
+            eparam("id", "bytes32", true),
+        ]
+    );
+    assert_eq!(
+        event.signature(),
+        "Transfer(address,address,uint256,bytes32)"
+    );
+
+    let event = Event::parse("Foo((uint256 a) indexed, uint8[] indexed)").unwrap();
+    assert!(event
+        .inputs
+        .iter()
+        .all(|param| param.indexed && param.name.is_empty()));
+    assert_eq!(event.signature(), "Foo((uint256),uint8[])");
+}
+
+#[test]
+fn parse_padded() {
+    let error = Error::parse("  Foo(uint256 a)  ").unwrap();
+    assert_eq!(error.name, "Foo");
+    assert_eq!(error.inputs, [param("a", "uint256")]);
+    assert_eq!(Error::parse("\tFoo ( uint256 a )\n"), Ok(error));
+
+    assert_eq!(
+        Function::parse("\n  foo(uint256)  view  \n")
+            .unwrap()
+            .state_mutability,
+        StateMutability::View
+    );
+    assert!(Event::parse("  Foo()  anonymous  ").unwrap().anonymous);
+
+    let err = Error::parse("  Foo(  ").unwrap_err();
+    assert!(err.to_string().contains("\"Foo(\""), "{err}");
+}
+
+#[test]
+fn parse_trailing_semicolon() {
+    for (with, without) in [
+        (
+            "function transfer(address,uint256) external returns (bool);",
+            "function transfer(address,uint256) external returns (bool)",
+        ),
+        (
+            "event Foo(uint256 indexed a) anonymous ;",
+            "event Foo(uint256 indexed a) anonymous",
+        ),
+        ("error Foo();\n", "error Foo()"),
+        (
+            "constructor(address) payable;",
+            "constructor(address) payable",
+        ),
+        ("receive() external payable;", "receive() external payable"),
+    ] {
+        assert_eq!(AbiItem::parse(with), AbiItem::parse(without), "{with}");
+        AbiItem::parse(without).unwrap();
+    }
+    assert_eq!(Function::parse("foo();"), Function::parse("foo()"));
+    assert_eq!(
+        Error::parse("Foo(uint256 a);"),
+        Error::parse("Foo(uint256 a)")
+    );
+
+    AbiItem::parse("function foo();;").unwrap_err();
+    Function::parse("foo(uint256;)").unwrap_err();
+}
+
+#[test]
+fn constructor_signature() {
+    let AbiItem::Constructor(constructor) =
+        AbiItem::parse("constructor(address owner, (uint a, bool[] b)[2] config) payable").unwrap()
+    else {
+        unreachable!()
+    };
+    assert_eq!(
+        constructor.signature(),
+        "constructor(address,(uint256,bool[])[2])"
+    );
+    assert_eq!(
+        Constructor {
+            inputs: vec![],
+            state_mutability: StateMutability::NonPayable
+        }
+        .signature(),
+        "constructor()"
+    );
+}
+
+#[test]
+fn parse_named_outputs() {
+    let f = Function::parse(
+        "getReserves(address pair) view returns (uint112 reserve0, uint112 reserve1, (uint32 timestamp, bool[] flags) info)",
+    )
+    .unwrap();
+    assert_eq!(f.outputs[0], param("reserve0", "uint112"));
+    assert_eq!(f.outputs[1], param("reserve1", "uint112"));
+    assert_eq!(f.outputs[2].name, "info");
+    assert_eq!(f.outputs[2].ty, "tuple");
+    assert_eq!(
+        f.outputs[2].components,
+        [param("timestamp", "uint32"), param("flags", "bool[]")]
+    );
+    assert_eq!(
+        f.signature_full(),
+        "getReserves(address)(uint112,uint112,(uint32,bool[]))"
+    );
+
+    // outputs are parsed exactly like inputs
+    let g = Function::parse("f(uint a, (bool b) c) returns (uint a, (bool b) c)").unwrap();
+    assert_eq!(g.inputs, g.outputs);
+}
+
+#[test]
+fn parse_qualified_struct_params() {
+    let f =
+        Function::parse("swap(IERC20.Foo memory x, Lib.Bar[2][] calldata ys, uint256 z)").unwrap();
+    assert_eq!(f.inputs[0].name, "x");
+    assert_eq!(f.inputs[0].ty, "tuple");
+    assert_eq!(
+        f.inputs[0].internal_type,
+        Some(InternalType::Struct {
+            contract: Some("IERC20".into()),
+            ty: "Foo".into()
+        })
+    );
+    assert_eq!(f.inputs[1].name, "ys");
+    assert_eq!(f.inputs[1].ty, "tuple[2][]");
+    assert_eq!(
+        f.inputs[1].internal_type,
+        Some(InternalType::Struct {
+            contract: Some("Lib".into()),
+            ty: "Bar[2][]".into()
+        })
+    );
+    assert_eq!(f.inputs[2], param("z", "uint256"));
+
+    let e = Event::parse("Filled(IERC20.Foo indexed order)").unwrap();
+    assert!(e.inputs[0].indexed);
+    assert_eq!(e.inputs[0].ty, "tuple");
+
+    // the components are unknown, so the signature is invalid
+    assert_eq!(f.signature(), "swap(tuple,tuple[2][],uint256)");
+    let violations = AbiItem::from(&f).validate_spec().unwrap_err();
+    assert!(matches!(
+        &violations[..],
+        [
+            SpecViolation::TupleWithoutComponents { .. },
+            SpecViolation::TupleWithoutComponents { .. }
+        ]
+    ));
+
+    // and are resolved through a registry, by qualified or unqualified name
+    let registry = BTreeMap::from([
+        (
+            "IERC20.Foo".to_string(),
+            vec![param("a", "address"), param("b", "uint256")],
+        ),
+        ("Bar".to_string(), vec![param("c", "bool")]),
+    ]);
+    let e =
+        Error::parse_with_registry("Bad(IERC20.Foo x, Lib.Bar[2][] ys, Other.Baz z)", &registry)
+            .unwrap();
+    assert_eq!(e.signature(), "Bad((address,uint256),(bool)[2][],tuple)");
+    assert_eq!(
+        e.inputs[1].internal_type,
+        Some(InternalType::Struct {
+            contract: Some("Lib".into()),
+            ty: "Bar[2][]".into()
+        })
+    );
+    assert_eq!(AbiItem::from(&e).validate_spec().unwrap_err().len(), 1);
+
+    for invalid in [
+        "f(IERC20. x)",
+        "f(.Foo x)",
+        "f(A.B.C x)",
+        "f(IERC20.Foo[x] x)",
+    ] {
+        Function::parse(invalid).unwrap_err();
+    }
+}
+
+#[test]
+fn parse_non_decimal_array_sizes() {
+    for (s, size) in [
+        ("f(uint256[0x10] x)", "0x10"),
+        ("f(uint256[1_0] x)", "1_0"),
+        ("f(bool, (address, bytes32[0X2]) y)", "0X2"),
+    ] {
+        let err = Function::parse(s).unwrap_err().to_string();
+        assert!(
+            err.contains(&format!("invalid array size `[{size}]`")),
+            "{s}: {err}"
+        );
+    }
+    Event::parse("E(uint8[0b11] indexed x)").unwrap_err();
+}
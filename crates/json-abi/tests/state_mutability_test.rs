@@ -27,3 +27,23 @@ fn state_mutability() {
 
     assert_json_eq!(json, &serde_json::to_string(&deserialized).unwrap());
 }
+
+#[test]
+fn state_mutability_from_str() {
+    for (s, expected) in [
+        ("pure", StateMutability::Pure),
+        ("VIEW", StateMutability::View),
+        ("constant", StateMutability::View),
+        ("nonpayable", StateMutability::NonPayable),
+        ("Non-Payable", StateMutability::NonPayable),
+        ("mutable", StateMutability::NonPayable),
+        ("payable", StateMutability::Payable),
+    ] {
+        assert_eq!(StateMutability::try_from(s), Ok(expected));
+        assert_eq!(s.parse::<StateMutability>(), Ok(expected));
+    }
+
+    let err = StateMutability::try_from("external").unwrap_err();
+    assert!(err.to_string().contains("\"external\""), "{err}");
+    "".parse::<StateMutability>().unwrap_err();
+}
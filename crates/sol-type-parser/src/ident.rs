@@ -51,6 +51,173 @@ pub const fn is_valid_identifier(s: &str) -> bool {
     true
 }
 
+/// Solidity keywords and words reserved for future use, sorted.
+///
+/// Elementary type names with a size suffix, such as `uint8` or `bytes32`,
+/// are handled separately in [`is_reserved_word`].
+///
+/// <https://docs.soliditylang.org/en/latest/cheatsheet.html#reserved-keywords>
+const RESERVED_WORDS: &[&str] = &[
+    "abstract",
+    "address",
+    "after",
+    "alias",
+    "anonymous",
+    "apply",
+    "as",
+    "assembly",
+    "auto",
+    "bool",
+    "break",
+    "byte",
+    "bytes",
+    "calldata",
+    "case",
+    "catch",
+    "constant",
+    "constructor",
+    "continue",
+    "contract",
+    "copyof",
+    "days",
+    "default",
+    "define",
+    "delete",
+    "do",
+    "else",
+    "emit",
+    "enum",
+    "ether",
+    "event",
+    "external",
+    "fallback",
+    "false",
+    "final",
+    "fixed",
+    "for",
+    "function",
+    "gwei",
+    "hex",
+    "hours",
+    "if",
+    "immutable",
+    "implements",
+    "import",
+    "in",
+    "indexed",
+    "inline",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "let",
+    "library",
+    "macro",
+    "mapping",
+    "match",
+    "memory",
+    "minutes",
+    "modifier",
+    "mutable",
+    "new",
+    "null",
+    "of",
+    "override",
+    "partial",
+    "payable",
+    "pragma",
+    "private",
+    "promise",
+    "public",
+    "pure",
+    "receive",
+    "reference",
+    "relocatable",
+    "return",
+    "returns",
+    "sealed",
+    "seconds",
+    "sizeof",
+    "static",
+    "storage",
+    "string",
+    "struct",
+    "supports",
+    "switch",
+    "true",
+    "try",
+    "type",
+    "typedef",
+    "typeof",
+    "ufixed",
+    "uint",
+    "unchecked",
+    "unicode",
+    "using",
+    "var",
+    "view",
+    "virtual",
+    "weeks",
+    "wei",
+    "while",
+    "years",
+];
+
+/// Returns `true` if the given string is a Solidity keyword, a word reserved
+/// for future use, or an elementary type name, such as `uint8` or `bytes32`.
+///
+/// Such words cannot be used as identifiers in Solidity source code, even
+/// though they are accepted by [`is_valid_identifier`].
+///
+/// # Examples
+///
+/// ```
+/// # use alloy_sol_type_parser::is_reserved_word;
+/// assert!(is_reserved_word("return"));
+/// assert!(is_reserved_word("bytes32"));
+/// assert!(is_reserved_word("ufixed128x18"));
+/// assert!(!is_reserved_word("balance"));
+/// assert!(!is_reserved_word("uint7"));
+/// ```
+pub fn is_reserved_word(s: &str) -> bool {
+    if RESERVED_WORDS.binary_search(&s).is_ok() {
+        return true
+    }
+
+    if crate::RootType::parse(s).map_or(false, |root| root.try_basic_solidity().is_ok()) {
+        return true
+    }
+
+    // `fixedMxN` and `ufixedMxN`
+    let s = s.strip_prefix('u').unwrap_or(s);
+    let Some((m, n)) = s.strip_prefix("fixed").and_then(|s| s.split_once('x')) else {
+        return false
+    };
+    let is_size = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    is_size(m) && is_size(n)
+}
+
+/// Returns `true` if the given string is a valid Solidity identifier that
+/// is not a reserved word.
+///
+/// This is a stricter version of [`is_valid_identifier`] which also rejects
+/// the words reported by [`is_reserved_word`]. It should be used when
+/// generating Solidity source code, while [`is_valid_identifier`] remains
+/// appropriate for ingesting ABIs.
+///
+/// # Examples
+///
+/// ```
+/// # use alloy_sol_type_parser::{is_valid_identifier, is_valid_identifier_strict};
+/// assert!(is_valid_identifier("address"));
+/// assert!(!is_valid_identifier_strict("address"));
+/// assert!(is_valid_identifier_strict("owner"));
+/// ```
+#[inline]
+pub fn is_valid_identifier_strict(s: &str) -> bool {
+    is_valid_identifier(s) && !is_reserved_word(s)
+}
+
 #[inline]
 pub(crate) fn parse_identifier<'a>(input: &mut &'a str) -> PResult<&'a str> {
     // See note in `is_valid_identifier` above.
@@ -96,6 +263,36 @@ mod tests {
         ident_test("foo3", Ok("foo3"), "");
     }
 
+    #[test]
+    fn test_reserved_words() {
+        assert!(RESERVED_WORDS.windows(2).all(|w| w[0] < w[1]));
+
+        for word in [
+            "function", "return", "address", "bool", "mapping", "var", "typeof",
+        ] {
+            assert!(is_reserved_word(word), "{word}");
+            assert!(is_valid_identifier(word), "{word}");
+            assert!(!is_valid_identifier_strict(word), "{word}");
+        }
+        for word in [
+            "uint8",
+            "int256",
+            "bytes1",
+            "bytes32",
+            "fixed128x18",
+            "ufixed8x0",
+        ] {
+            assert!(is_reserved_word(word), "{word}");
+        }
+        for word in [
+            "foo", "_return", "Address", "uint7", "bytes33", "fixedx18", "error",
+        ] {
+            assert!(!is_reserved_word(word), "{word}");
+            assert!(is_valid_identifier_strict(word), "{word}");
+        }
+        assert!(!is_valid_identifier_strict("3foo"));
+    }
+
     #[track_caller]
     fn ident_test(mut input: &str, expected: Result<&str, ()>, output: &str) {
         assert_eq!(
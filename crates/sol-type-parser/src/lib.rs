@@ -32,7 +32,10 @@ pub use error::{Error, Result};
 
 /// Solidity ident rules.
 mod ident;
-pub use ident::{is_id_continue, is_id_start, is_valid_identifier, IDENT_REGEX};
+pub use ident::{
+    is_id_continue, is_id_start, is_reserved_word, is_valid_identifier, is_valid_identifier_strict,
+    IDENT_REGEX,
+};
 
 /// Root type specifier.
 mod root;
@@ -83,9 +83,19 @@ impl AsRef<str> for TypeSpecifier<'_> {
 
 impl<'a> TypeSpecifier<'a> {
     /// Parse a type specifier from a string.
+    ///
+    /// Array sizes must be positive decimal integers: hexadecimal sizes like
+    /// `[0x10]` and separated sizes like `[1_000]` are rejected.
     #[inline]
     pub fn parse(input: &'a str) -> Result<Self> {
-        Self::parser.parse(input).map_err(Error::parser)
+        Self::parser
+            .parse(input)
+            .map_err(|e| match non_decimal_array_size(input) {
+                Some(size) => Error::new(format_args!(
+                "invalid array size `[{size}]` in {input:?}: array sizes must be decimal integers"
+            )),
+                None => Error::parser(e),
+            })
     }
 
     pub(crate) fn parser(input: &mut &'a str) -> PResult<Self> {
@@ -138,6 +148,16 @@ impl<'a> TypeSpecifier<'a> {
     }
 }
 
+/// Returns the first non-empty array size in `input` that is not a decimal
+/// integer, e.g. `0x10` in `uint256[0x10]`.
+fn non_decimal_array_size(input: &str) -> Option<&str> {
+    input
+        .split('[')
+        .skip(1)
+        .filter_map(|dim| dim.split_once(']').map(|(size, _)| size))
+        .find(|size| !size.is_empty() && !size.bytes().all(|b| b.is_ascii_digit()))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -249,4 +269,23 @@ mod test {
         TypeSpecifier::parse(&format!("a[{}]", usize::MAX)).unwrap();
         TypeSpecifier::parse(&format!("a[{}0]", usize::MAX)).unwrap_err();
     }
+
+    #[test]
+    fn non_decimal_sizes() {
+        for (ty, size) in [
+            ("uint256[0x10]", "0x10"),
+            ("uint256[1_0]", "1_0"),
+            ("uint256[2][0x1]", "0x1"),
+            ("uint256[][1e3]", "1e3"),
+            ("uint256[-1]", "-1"),
+        ] {
+            let err = TypeSpecifier::parse(ty).unwrap_err().to_string();
+            assert!(err.contains(&format!("`[{size}]`")), "{ty}: {err}");
+            assert!(err.contains("decimal"), "{ty}: {err}");
+        }
+
+        // other errors are reported as is
+        let err = TypeSpecifier::parse("uint256[0]").unwrap_err().to_string();
+        assert!(!err.contains("decimal"), "{err}");
+    }
 }